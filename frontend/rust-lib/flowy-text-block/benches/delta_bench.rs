@@ -0,0 +1,185 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lib_ot::{
+    core::{DeltaBuilder, OperationTransformable},
+    rich_text::{AttributeBuilder, RichTextAttribute, RichTextAttributes, RichTextDelta, RichTextDeltaBuilder},
+};
+use ropey::Rope;
+
+const OPS_COUNT: usize = 10_000;
+
+// The alternating attributes keep the adjacent operations from being merged, so both deltas
+// have 10k operations.
+fn ten_thousand_ops_deltas() -> (RichTextDelta, RichTextDelta) {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let italic = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Italic(true))
+        .build();
+    let mut document = RichTextDelta::new();
+    let mut format = RichTextDelta::new();
+    for i in 0..OPS_COUNT {
+        if i % 2 == 0 {
+            document.insert("a", bold.clone());
+            format.retain(1, italic.clone());
+        } else {
+            document.insert("b", RichTextAttributes::default());
+            format.retain(1, RichTextAttributes::default());
+        }
+    }
+    (document, format)
+}
+
+fn delta_benchmark(c: &mut Criterion) {
+    let (document, format) = ten_thousand_ops_deltas();
+    c.bench_function("compose 10k ops", |b| {
+        b.iter(|| black_box(&document).compose(black_box(&format)).unwrap())
+    });
+    c.bench_function("has_attribute of 10k ops", |b| {
+        b.iter(|| black_box(&document).ops.iter().filter(|op| op.has_attribute()).count())
+    });
+}
+
+// The trailing insert keeps the compose from taking the fast path of the format-only delta, so
+// both functions compose the same formatting.
+fn format_benchmark(c: &mut Criterion) {
+    let (document, format) = ten_thousand_ops_deltas();
+    let mut general_format = format.clone();
+    general_format.insert("c", RichTextAttributes::default());
+
+    let mut group = c.benchmark_group("compose 10k format-only ops");
+    group.bench_function("general", |b| {
+        b.iter(|| black_box(&document).compose(black_box(&general_format)).unwrap())
+    });
+    group.bench_function("attributes only", |b| {
+        b.iter(|| {
+            black_box(&document)
+                .compose_attributes_only(black_box(&format))
+                .unwrap()
+        })
+    });
+    group.finish();
+}
+
+// 10k single character inserts typed one after another, each one is based on the document
+// produced by the previous one.
+fn typing_deltas() -> Vec<RichTextDelta> {
+    (0..OPS_COUNT)
+        .map(|i| DeltaBuilder::new().retain(i).insert("a").build())
+        .collect()
+}
+
+fn fold_benchmark(c: &mut Criterion) {
+    let deltas = typing_deltas();
+    let mut group = c.benchmark_group("fold 10k typing deltas");
+    group.sample_size(10);
+    group.bench_function("compose", |b| {
+        b.iter(|| {
+            deltas
+                .iter()
+                .fold(RichTextDelta::new(), |document, delta| document.compose(delta).unwrap())
+        })
+    });
+    group.bench_function("compose in place", |b| {
+        b.iter(|| {
+            deltas.iter().fold(RichTextDelta::new(), |mut document, delta| {
+                document.compose_in_place(delta).unwrap();
+                document
+            })
+        })
+    });
+    group.finish();
+}
+
+// 1,000 single character inserts spread over a 1MB document, each one is based on the document
+// produced by the previous one.
+fn small_edits(document_len: usize) -> Vec<RichTextDelta> {
+    (0..1_000)
+        .map(|i| {
+            let len = document_len + i;
+            let index = (i * 7919) % len;
+            DeltaBuilder::new()
+                .retain(index)
+                .insert("x")
+                .retain(len - index)
+                .build()
+        })
+        .collect()
+}
+
+fn apply_benchmark(c: &mut Criterion) {
+    let document = "a".repeat(1024 * 1024);
+    let edits = small_edits(document.len());
+    let rope = Rope::from_str(&document);
+
+    let mut group = c.benchmark_group("apply 1,000 edits to 1MB");
+    group.sample_size(10);
+    group.bench_function("string", |b| {
+        b.iter(|| edits.iter().fold(document.clone(), |s, edit| edit.apply(&s).unwrap()))
+    });
+    group.bench_function("rope", |b| {
+        b.iter(|| edits.iter().fold(rope.clone(), |r, edit| edit.apply_rope(&r).unwrap()))
+    });
+    group.finish();
+}
+
+// 100k lines imported with the alternating attributes, so none of the operations is merged.
+const LINES_COUNT: usize = 100_000;
+
+fn build_lines(builder: RichTextDeltaBuilder, bold: &RichTextAttributes) -> RichTextDelta {
+    (0..LINES_COUNT)
+        .fold(builder, |builder, i| match i % 2 {
+            0 => builder.insert_with_attributes("line\n", bold.clone()),
+            _ => builder.insert("line\n"),
+        })
+        .build()
+}
+
+fn build_benchmark(c: &mut Criterion) {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut group = c.benchmark_group("build 100k lines");
+    group.sample_size(10);
+    group.bench_function("new", |b| b.iter(|| build_lines(DeltaBuilder::new(), &bold)));
+    group.bench_function("with capacity", |b| {
+        b.iter(|| build_lines(DeltaBuilder::with_capacity(LINES_COUNT), &bold))
+    });
+    group.finish();
+}
+
+// One retain of 100k composed against 1,000 tiny operations, the long retain is consumed by
+// every one of them.
+const RETAIN_LEN: usize = 100_000;
+
+fn tiny_ops_delta() -> RichTextDelta {
+    let italic = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Italic(true))
+        .build();
+    let mut delta = RichTextDelta::new();
+    for i in 0..1_000 {
+        match i % 3 {
+            0 => delta.retain(1, italic.clone()),
+            1 => delta.insert("x", RichTextAttributes::default()),
+            _ => delta.delete(1),
+        }
+    }
+    delta.retain(RETAIN_LEN - delta.utf16_base_len, RichTextAttributes::default());
+    delta
+}
+
+fn compose_retain_benchmark(c: &mut Criterion) {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let retain = DeltaBuilder::new().retain_with_attributes(RETAIN_LEN, bold).build();
+    let tiny_ops = tiny_ops_delta();
+    c.bench_function("compose retain(100k) with 1,000 tiny ops", |b| {
+        b.iter(|| black_box(&retain).compose(black_box(&tiny_ops)).unwrap())
+    });
+}
+
+criterion_group!(
+    benches,
+    delta_benchmark,
+    format_benchmark,
+    fold_benchmark,
+    apply_benchmark,
+    build_benchmark,
+    compose_retain_benchmark
+);
+criterion_main!(benches);
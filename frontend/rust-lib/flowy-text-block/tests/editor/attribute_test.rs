@@ -1,9 +1,10 @@
 #![cfg_attr(rustfmt, rustfmt::skip)]
 use crate::editor::{TestBuilder, TestOp::*};
 use flowy_sync::client_document::{NewlineDoc, PlainDoc};
-use lib_ot::core::{Interval, OperationTransformable, NEW_LINE, WHITESPACE, FlowyStr};
+use lib_ot::core::{Attributes, DeltaBuilder, Interval, OpBuilder, OperationTransformable, NEW_LINE, WHITESPACE, FlowyStr};
 use unicode_segmentation::UnicodeSegmentation;
-use lib_ot::rich_text::RichTextDelta;
+use lib_ot::rich_text::{AttributeBuilder, AttributeScope, RichTextAttribute, RichTextAttributeKey, RichTextAttributeValue, RichTextAttributes, RichTextDelta, RichTextOperation};
+use lib_ot::errors::OTErrorCode;
 
 #[test]
 fn attributes_bold_added() {
@@ -798,3 +799,324 @@ fn delta_compose() {
 
     TestBuilder::new().run_scripts::<NewlineDoc>(ops);
 }
+
+#[test]
+fn attributes_compose_with_removal() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut unbold = RichTextAttributes::new();
+    unbold.delete(&RichTextAttributeKey::Bold);
+
+    // The removal is kept after composing, the retain needs it to clear the formatting.
+    let mut composed = bold.compose(&unbold).unwrap();
+    assert_eq!(composed, unbold);
+    composed.remove_empty();
+    assert!(composed.is_empty());
+
+    let mut attributes = bold.compose(&AttributeBuilder::new().add_attr(RichTextAttribute::Italic(true)).build()).unwrap();
+    attributes.remove(RichTextAttributeKey::Bold);
+    attributes.remove(RichTextAttributeKey::Italic);
+    assert!(attributes.is_empty());
+}
+
+#[test]
+fn attributes_diff() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let bold_italic = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Bold(true))
+        .add_attr(RichTextAttribute::Italic(true))
+        .build();
+    let header = AttributeBuilder::new().add_attr(RichTextAttribute::Header(1)).build();
+
+    assert!(bold.diff(&bold).is_empty());
+    assert_eq!(bold.diff(&bold_italic), AttributeBuilder::new().add_attr(RichTextAttribute::Italic(true)).build());
+
+    let mut unbold = RichTextAttributes::new();
+    unbold.delete(&RichTextAttributeKey::Bold);
+    assert_eq!(bold_italic.diff(&AttributeBuilder::new().add_attr(RichTextAttribute::Italic(true)).build()), unbold);
+
+    let mut changed = header.clone();
+    changed.insert(RichTextAttributeKey::Header, 2_usize.into());
+    assert_eq!(header.diff(&changed), changed);
+}
+
+#[test]
+fn attributes_transform_with_priority() {
+    let red = AttributeBuilder::new().add_attr(RichTextAttribute::Color("red".to_owned())).build();
+    let blue_bold = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Color("blue".to_owned()))
+        .add_attr(RichTextAttribute::Bold(true))
+        .build();
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+
+    assert_eq!(red.transform_with_priority(&blue_bold, true), bold);
+    assert_eq!(red.transform_with_priority(&blue_bold, false), blue_bold);
+
+    // The removal takes precedence the same as the other values.
+    let mut unbold = RichTextAttributes::new();
+    unbold.delete(&RichTextAttributeKey::Bold);
+    assert!(unbold.transform_with_priority(&bold, true).is_empty());
+    assert_eq!(bold.transform_with_priority(&unbold, false), unbold);
+}
+
+#[test]
+fn operation_attribute_value() {
+    let attributes = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).add_attr(RichTextAttribute::Header(2)).build();
+    let op = OpBuilder::insert("123").attributes(attributes).build();
+    assert!(op.has_attribute_key(&RichTextAttributeKey::Bold));
+    assert_eq!(op.attribute_value(&RichTextAttributeKey::Header), Some(&RichTextAttributeValue::Int(2)));
+    assert!(!op.has_attribute_key(&RichTextAttributeKey::Italic));
+    assert_eq!(op.attribute_value(&RichTextAttributeKey::Italic), None);
+
+    let op: RichTextOperation = OpBuilder::insert("123").build();
+    assert!(!op.has_attribute_key(&RichTextAttributeKey::Bold));
+    assert_eq!(op.attribute_value(&RichTextAttributeKey::Bold), None);
+
+    let op: RichTextOperation = OpBuilder::delete(3).build();
+    assert_eq!(op.attribute_value(&RichTextAttributeKey::Bold), None);
+}
+
+#[test]
+fn attribute_value_typed_accessors() {
+    let bold: RichTextAttributeValue = true.into();
+    assert_eq!(bold.as_bool(), Some(true));
+    assert_eq!(bold.as_usize(), None);
+    assert!(!bold.is_null());
+
+    let header: RichTextAttributeValue = 2_usize.into();
+    assert_eq!(header.as_usize(), Some(2));
+    assert_eq!(header.as_bool(), None);
+
+    let color: RichTextAttributeValue = "red".into();
+    assert_eq!(color.as_str(), Some("red"));
+
+    // The false is a value, only the toggle attributes turn it into the removal.
+    let unset: RichTextAttributeValue = false.into();
+    assert!(!unset.is_null());
+    assert_eq!(unset.as_bool(), Some(false));
+    assert_eq!(unset.as_str(), None);
+    assert!(RichTextAttribute::Bold(false).value.is_null());
+
+    let removed: RichTextAttributeValue = 0_usize.into();
+    assert!(removed.is_null());
+    assert_eq!(removed.as_usize(), None);
+}
+
+#[test]
+fn operation_invert_attributes() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let unbold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(false)).build();
+    assert_eq!(unbold.get(&RichTextAttributeKey::Bold), Some(&RichTextAttributeValue::Null));
+
+    // Bolding the plain text is undone by the removal of the bold.
+    let op = OpBuilder::retain(3).attributes(bold.clone()).build();
+    assert_eq!(op.invert_attributes(&RichTextAttributes::default()), unbold);
+
+    // Removing the bold is undone by bolding the text again.
+    let op = OpBuilder::retain(3).attributes(unbold.clone()).build();
+    assert_eq!(op.invert_attributes(&bold), bold);
+
+    // The keys that aren't changed by the operation are left as they are.
+    let base = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).add_attr(RichTextAttribute::Italic(true)).build();
+    let op = OpBuilder::retain(3).attributes(unbold).build();
+    assert_eq!(op.invert_attributes(&base), bold);
+
+    let op: RichTextOperation = OpBuilder::delete(3).build();
+    assert!(op.invert_attributes(&bold).is_empty());
+}
+
+#[test]
+fn delta_optimize() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let unbold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(false)).build();
+    let base: RichTextDelta = DeltaBuilder::new().insert("123").insert_with_attributes("456", bold.clone()).build();
+
+    // Bolding and then unbolding the plain text changes nothing.
+    let a: RichTextDelta = DeltaBuilder::new().retain_with_attributes(3, bold.clone()).build();
+    let b: RichTextDelta = DeltaBuilder::new().retain_with_attributes(3, unbold.clone()).build();
+    let mut delta = a.compose(&b).unwrap();
+    assert_eq!(delta, DeltaBuilder::new().retain_with_attributes(3, unbold.clone()).build());
+    delta.optimize(&base);
+    assert_eq!(delta, DeltaBuilder::new().retain(3).build());
+    assert!(delta.is_noop());
+
+    // Bolding "2345" only bolds "23", the "45" is bold already.
+    let mut delta: RichTextDelta = DeltaBuilder::new().retain(1).retain_with_attributes(4, bold.clone()).insert("7").retain(1).build();
+    let expected = base.compose(&delta).unwrap();
+    delta.optimize(&base);
+    assert_eq!(delta, DeltaBuilder::new().retain(1).retain_with_attributes(2, bold.clone()).retain(2).insert("7").retain(1).build());
+    assert_eq!(base.compose(&delta).unwrap(), expected);
+
+    // Unbolding "3456" only unbolds "456".
+    let mut delta: RichTextDelta = DeltaBuilder::new().retain(2).retain_with_attributes(4, unbold.clone()).build();
+    delta.optimize(&base);
+    assert_eq!(delta, DeltaBuilder::new().retain(3).retain_with_attributes(3, unbold).build());
+}
+
+#[test]
+fn delta_compose_attribute_removal() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let unbold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(false)).build();
+
+    // The removal over the bold insert leaves the plain insert, not the insert with `bold: null`.
+    let a: RichTextDelta = DeltaBuilder::new().insert_with_attributes("123", bold.clone()).build();
+    let b: RichTextDelta = DeltaBuilder::new().retain_with_attributes(3, unbold.clone()).build();
+    let composed = a.compose(&b).unwrap();
+    assert_eq!(composed, DeltaBuilder::new().insert("123").build());
+    assert!(!composed.ops[0].has_attribute());
+    // The same goes for the general compose, which the trailing insert forces.
+    let b: RichTextDelta = DeltaBuilder::new().retain_with_attributes(3, unbold.clone()).insert("4").build();
+    assert_eq!(a.compose(&b).unwrap(), DeltaBuilder::new().insert("1234").build());
+
+    // The composed retain keeps the removal, it still unbolds the document it's applied to.
+    let a: RichTextDelta = DeltaBuilder::new().retain_with_attributes(3, bold.clone()).build();
+    let b: RichTextDelta = DeltaBuilder::new().retain_with_attributes(3, unbold.clone()).build();
+    let composed = a.compose(&b).unwrap();
+    assert_eq!(composed, DeltaBuilder::new().retain_with_attributes(3, unbold.clone()).build());
+    let document: RichTextDelta = DeltaBuilder::new().insert_with_attributes("123", bold).build();
+    assert_eq!(document.compose(&a).unwrap().compose(&b).unwrap(), document.compose(&composed).unwrap());
+    assert_eq!(document.compose(&composed).unwrap(), DeltaBuilder::new().insert("123").build());
+
+    // The removal over the plain text changes nothing.
+    let document: RichTextDelta = DeltaBuilder::new().insert("123").build();
+    assert_eq!(document.compose(&b).unwrap(), document);
+}
+
+#[test]
+fn delta_diff_attributes() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let italic = AttributeBuilder::new().add_attr(RichTextAttribute::Italic(true)).build();
+    let unbold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(false)).build();
+    let a: RichTextDelta = DeltaBuilder::new().insert_with_attributes("123", bold.clone()).insert("456").insert("\n").build();
+    let b: RichTextDelta = DeltaBuilder::new().insert("12").insert_with_attributes("3", bold.clone()).insert_with_attributes("45", italic.clone()).insert("6\n").build();
+
+    let diff = a.diff_attributes(&b).unwrap();
+    assert_eq!(
+        diff,
+        DeltaBuilder::new().retain_with_attributes(2, unbold).retain(1).retain_with_attributes(2, italic).retain(2).build()
+    );
+    assert_eq!(a.compose(&diff).unwrap(), b);
+    assert!(a.diff_attributes(&a).unwrap().is_noop());
+
+    let err = a.diff_attributes(&DeltaBuilder::new().insert("1234567").build()).unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::ApplyFormatFail));
+    assert!(a.diff_attributes(&DeltaBuilder::new().insert("123456").build()).is_err());
+    assert!(a.diff_attributes(&DeltaBuilder::new().insert("123456\n\n").build()).is_err());
+}
+
+#[test]
+fn attributes_scope_subsets() {
+    assert_eq!(RichTextAttributeKey::Header.scope(), AttributeScope::Block);
+    assert_eq!(RichTextAttributeKey::List.scope(), AttributeScope::Block);
+    assert_eq!(RichTextAttributeKey::Bold.scope(), AttributeScope::Inline);
+    assert_eq!(RichTextAttributeKey::Width.scope(), AttributeScope::Ignore);
+
+    let attributes = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Bold(true))
+        .add_attr(RichTextAttribute::Header(1))
+        .add_attr(RichTextAttribute::Width(100))
+        .build();
+    let header: RichTextAttributes = RichTextAttribute::Header(1).into();
+    let bold: RichTextAttributes = RichTextAttribute::Bold(true).into();
+    assert_eq!(attributes.block_subset(), header);
+    assert_eq!(attributes.inline_subset(), bold);
+}
+
+#[test]
+fn attributes_register_custom_key() {
+    let json = r#"[{"insert":"@appflowy","attributes":{"mention":"user_1"}},{"insert":"\n","attributes":{"callout":true}}]"#;
+    // The unregistered keys are kept with the ignore scope.
+    let delta = RichTextDelta::from_delta_str(json).unwrap();
+    assert_eq!(delta.to_delta_str(), json);
+    assert_eq!(RichTextAttributeKey::from_name("mention"), None);
+    assert_eq!(
+        RichTextAttributeKey::Custom("mention".to_owned()).scope(),
+        AttributeScope::Ignore
+    );
+
+    let mention = RichTextAttributeKey::register("mention", AttributeScope::Inline).unwrap();
+    let callout = RichTextAttributeKey::register("callout", AttributeScope::Block).unwrap();
+    assert_eq!(mention.scope(), AttributeScope::Inline);
+    assert_eq!(callout.scope(), AttributeScope::Block);
+    assert_eq!(RichTextAttributeKey::from_name("callout"), Some(callout.clone()));
+    assert_eq!(RichTextAttributeKey::from_name("strike"), Some(RichTextAttributeKey::StrikeThrough));
+    let err = RichTextAttributeKey::register("bold", AttributeScope::Block).unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::InvalidOperation));
+    assert_eq!(RichTextAttributeKey::Bold.scope(), AttributeScope::Inline);
+
+    let delta = RichTextDelta::from_delta_str(json).unwrap();
+    assert_eq!(delta.to_delta_str(), json);
+    let mut attributes = RichTextAttributes::new();
+    attributes.insert(mention.clone(), "user_1".into());
+    attributes.insert(callout.clone(), true.into());
+    assert_eq!(attributes.block_subset().keys().collect::<Vec<_>>(), vec![&callout]);
+    assert_eq!(attributes.inline_subset().keys().collect::<Vec<_>>(), vec![&mention]);
+}
+
+#[test]
+fn delta_format_routes_block_attributes() {
+    let document: RichTextDelta = DeltaBuilder::new().insert("123\n456\n789").build();
+    let attributes = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Bold(true))
+        .add_attr(RichTextAttribute::Header(1))
+        .build();
+    let bold: RichTextAttributes = RichTextAttribute::Bold(true).into();
+    let header: RichTextAttributes = RichTextAttribute::Header(1).into();
+
+    // The bold formats the range, the header formats the newlines of the touched lines.
+    let delta = document.format(2, 5, attributes.clone()).unwrap();
+    assert_eq!(
+        delta,
+        DeltaBuilder::new()
+            .retain(2)
+            .retain_with_attributes(1, bold.clone())
+            .retain_with_attributes(1, attributes.clone())
+            .retain_with_attributes(1, bold.clone())
+            .retain(2)
+            .retain_with_attributes(1, header.clone())
+            .retain(3)
+            .build()
+    );
+    let formatted = document.compose(&delta).unwrap();
+    let mut lines = vec![];
+    formatted.each_line(|line, attributes| {
+        lines.push((line.apply("").unwrap(), attributes.clone()));
+        true
+    });
+    assert_eq!(
+        lines,
+        vec![
+            ("123".to_owned(), header.clone()),
+            ("456".to_owned(), header.clone()),
+            ("789".to_owned(), RichTextAttributes::default()),
+        ]
+    );
+
+    // The empty range formats the line it's in, the last line has no newline to carry the header.
+    let delta = document.format(5, 5, header.clone()).unwrap();
+    assert_eq!(delta, DeltaBuilder::new().retain(7).retain_with_attributes(1, header.clone()).retain(3).build());
+    let delta = document.format(9, 10, header).unwrap();
+    assert_eq!(delta, DeltaBuilder::new().retain(11).build());
+}
+
+#[test]
+fn attributes_merge_all() {
+    let bold_italic = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).add_attr(RichTextAttribute::Italic(true)).build();
+    let italic: RichTextAttributes = RichTextAttribute::Italic(true).into();
+    let (common, mixed) = RichTextAttributes::merge_all(vec![&bold_italic, &italic]);
+    assert_eq!(common, italic);
+    assert_eq!(mixed.into_iter().collect::<Vec<_>>(), vec![RichTextAttributeKey::Bold]);
+
+    // The other order, and the differing values are mixed too.
+    let h1: RichTextAttributes = RichTextAttribute::Header(1).into();
+    let h2: RichTextAttributes = RichTextAttribute::Header(2).into();
+    let (common, mixed) = RichTextAttributes::merge_all(vec![&italic, &bold_italic, &h1, &h2]);
+    assert!(common.is_empty());
+    assert_eq!(mixed.len(), 3);
+    assert!(mixed.contains(&RichTextAttributeKey::Header));
+
+    let (common, mixed) = RichTextAttributes::merge_all(vec![&bold_italic, &bold_italic]);
+    assert_eq!(common, bold_italic);
+    assert!(mixed.is_empty());
+    let (common, mixed) = RichTextAttributes::merge_all(Vec::<&RichTextAttributes>::new());
+    assert!(common.is_empty() && mixed.is_empty());
+}
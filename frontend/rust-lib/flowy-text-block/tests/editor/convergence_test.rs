@@ -0,0 +1,200 @@
+use crate::editor::Rng;
+use lib_ot::{core::*, rich_text::RichTextDelta};
+use rand::{prelude::*, Rng as WrappedRng};
+
+#[test]
+fn converge_two_peers() {
+    ConvergenceTest {
+        peers: 2,
+        steps: 500,
+        seed: 1,
+    }
+    .run();
+}
+
+#[test]
+fn converge_many_peers() {
+    for seed in 0..20 {
+        ConvergenceTest {
+            peers: 5,
+            steps: 500,
+            seed,
+        }
+        .run();
+    }
+}
+
+// Set the `CONVERGENCE_SEED` to replay the seed of the failure.
+#[test]
+fn converge_random_seed() {
+    let seed = match std::env::var("CONVERGENCE_SEED") {
+        Ok(seed) => seed.parse().expect("CONVERGENCE_SEED must be an u64"),
+        Err(_) => thread_rng().gen(),
+    };
+    ConvergenceTest {
+        peers: 3,
+        steps: 1000,
+        seed,
+    }
+    .run();
+}
+
+// Simulates the peers editing the same document through the server. Every step, one of the peers
+// edits its document, sends its edits or receives the next revision from the server, or the
+// server receives one of the sent edits, picked randomly so their delivery order is shuffled.
+// The edits are small and local like the typing, so the documents stay small. When the steps are
+// done, everything left is delivered and all the documents must be the same. The failure message
+// contains the seed, the same seed replays the same steps.
+struct ConvergenceTest {
+    peers: usize,
+    steps: usize,
+    seed: u64,
+}
+
+impl ConvergenceTest {
+    fn run(&self) {
+        let mut rng = Rng(StdRng::seed_from_u64(self.seed));
+        let document: RichTextDelta = DeltaBuilder::new().insert(&rng.gen_string(20)).build();
+        let mut server = Server::new(document.clone());
+        let mut peers: Vec<Peer> = (0..self.peers).map(|id| Peer::new(id, document.clone())).collect();
+
+        for _ in 0..self.steps {
+            let peer = &mut peers[rng.0.gen_range(0..self.peers)];
+            match rng.0.gen_range(0..4) {
+                0 => peer.edit(&mut rng),
+                1 => {
+                    if let Some(sent) = peer.send() {
+                        server.inbox.push(sent);
+                    }
+                }
+                2 => server.receive_any(&mut rng),
+                _ => {
+                    peer.pull(&server);
+                }
+            }
+        }
+
+        loop {
+            for peer in peers.iter_mut() {
+                while peer.pull(&server) {}
+                if let Some(sent) = peer.send() {
+                    server.inbox.push(sent);
+                }
+            }
+            if server.inbox.is_empty() {
+                break;
+            }
+            while !server.inbox.is_empty() {
+                server.receive_any(&mut rng);
+            }
+        }
+
+        for peer in &peers {
+            assert_eq!(
+                peer.document, server.document,
+                "peer {} diverged, peers: {}, steps: {}, seed: {}",
+                peer.id, self.peers, self.steps, self.seed
+            );
+        }
+    }
+}
+
+struct Server {
+    document: RichTextDelta,
+    // The applied deltas along with the peers that made them, the index is the revision.
+    history: Vec<(usize, RichTextDelta)>,
+    // The sent deltas that aren't received yet: the peer, the revision the delta is based on and
+    // the delta.
+    inbox: Vec<(usize, usize, RichTextDelta)>,
+}
+
+impl Server {
+    fn new(document: RichTextDelta) -> Self {
+        Self {
+            document,
+            history: vec![],
+            inbox: vec![],
+        }
+    }
+
+    // Every peer has one delta in flight at most, so picking any of them keeps the order of the
+    // deltas sent by the same peer.
+    fn receive_any(&mut self, rng: &mut Rng) {
+        if self.inbox.is_empty() {
+            return;
+        }
+        let (peer, rev, mut delta) = self.inbox.swap_remove(rng.0.gen_range(0..self.inbox.len()));
+        for (_, applied) in &self.history[rev..] {
+            delta = applied.transform(&delta).unwrap().1;
+        }
+        self.document = self.document.compose(&delta).unwrap();
+        self.history.push((peer, delta));
+    }
+}
+
+struct Peer {
+    id: usize,
+    document: RichTextDelta,
+    // The number of the server revisions the document contains.
+    rev: usize,
+    // The delta sent to the server that isn't acknowledged yet.
+    inflight: Option<RichTextDelta>,
+    // The edits made after the inflight delta is sent.
+    buffer: Option<RichTextDelta>,
+}
+
+impl Peer {
+    fn new(id: usize, document: RichTextDelta) -> Self {
+        Self {
+            id,
+            document,
+            rev: 0,
+            inflight: None,
+            buffer: None,
+        }
+    }
+
+    fn edit(&mut self, rng: &mut Rng) {
+        let delta = rng.gen_local_edit(self.document.utf16_target_len);
+        self.document = self.document.compose(&delta).unwrap();
+        self.buffer = Some(match self.buffer.take() {
+            Some(buffer) => buffer.compose(&delta).unwrap(),
+            None => delta,
+        });
+    }
+
+    fn send(&mut self) -> Option<(usize, usize, RichTextDelta)> {
+        if self.inflight.is_some() {
+            return None;
+        }
+        let delta = self.buffer.take()?;
+        self.inflight = Some(delta.clone());
+        Some((self.id, self.rev, delta))
+    }
+
+    // Receives the next revision from the server, returns false if there is none. The revision
+    // made by the peer itself acknowledges the inflight delta, the others are transformed against
+    // the local edits the server hasn't seen. Like the server, the revision goes first on ties.
+    fn pull(&mut self, server: &Server) -> bool {
+        let (author, revision) = match server.history.get(self.rev) {
+            Some(revision) => revision,
+            None => return false,
+        };
+        self.rev += 1;
+        if *author == self.id {
+            self.inflight = None;
+            return true;
+        }
+
+        let mut delta = revision.clone();
+        for pending in [&mut self.inflight, &mut self.buffer] {
+            if let Some(pending) = pending.as_mut() {
+                let (delta_prime, pending_prime) = delta.transform(pending).unwrap();
+                delta = delta_prime;
+                *pending = pending_prime;
+            }
+        }
+        self.document = self.document.compose(&delta).unwrap();
+        true
+    }
+}
@@ -0,0 +1,86 @@
+use lib_ot::rich_text::RichTextDelta;
+
+#[test]
+fn html_export_inline_attributes() {
+    let delta = RichTextDelta::from_delta_str(
+        r#"[
+        {"insert":"a < b & "},
+        {"insert":"bold","attributes":{"bold":true}},
+        {"insert":" "},
+        {"insert":"both","attributes":{"bold":true,"italic":true}},
+        {"insert":" "},
+        {"insert":"code","attributes":{"code":true}},
+        {"insert":" "},
+        {"insert":"link","attributes":{"link":"https://appflowy.io/?a=\"1\"&b=2"}},
+        {"insert":"\n"}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        delta.to_html(),
+        "<p>a &lt; b &amp; <strong>bold</strong> <strong><em>both</em></strong> <code>code</code> \
+        <a href=\"https://appflowy.io/?a=&quot;1&quot;&amp;b=2\">link</a></p>"
+    );
+}
+
+#[test]
+fn html_export_group_lines() {
+    let delta = RichTextDelta::from_delta_str(
+        r#"[
+        {"insert":"Title"},
+        {"insert":"\n","attributes":{"header":1}},
+        {"insert":"first"},
+        {"insert":"\n","attributes":{"list":"bullet"}},
+        {"insert":"second","attributes":{"bold":true}},
+        {"insert":"\n","attributes":{"list":"bullet"}},
+        {"insert":"one"},
+        {"insert":"\n","attributes":{"list":"ordered"}},
+        {"insert":"two"},
+        {"insert":"\n","attributes":{"list":"ordered"}},
+        {"insert":"quote"},
+        {"insert":"\n","attributes":{"blockquote":true}},
+        {"insert":"\nlast\n"}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        delta.to_html(),
+        "<h1>Title</h1>\
+        <ul><li>first</li><li><strong>second</strong></li></ul>\
+        <ol><li>one</li><li>two</li></ol>\
+        <blockquote>quote</blockquote>\
+        <p><br></p><p>last</p>"
+    );
+}
+
+#[test]
+fn html_export_list_at_the_end() {
+    let delta = RichTextDelta::from_delta_str(
+        r#"[
+        {"insert":"text\nitem"},
+        {"insert":"\n","attributes":{"list":"checked"}}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(delta.to_html(), "<p>text</p><ul><li>item</li></ul>");
+}
+
+#[test]
+fn html_export_drop_unsafe_urls() {
+    let delta = RichTextDelta::from_delta_str(
+        r#"[
+        {"insert":"a","attributes":{"link":"javascript:alert(1)"}},
+        {"insert":"b","attributes":{"link":" JAVA\tSCRIPT:alert(1)"}},
+        {"insert":"c","attributes":{"link":"mailto:hello@appflowy.io"}},
+        {"insert":{"image":"javascript:alert(1)"}},
+        {"insert":{"image":"data:image/png;base64,AAAA"}},
+        {"insert":{"image":"data:text/html,<script>"}},
+        {"insert":"\n"}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        delta.to_html(),
+        "<p>ab<a href=\"mailto:hello@appflowy.io\">c</a><img src=\"data:image/png;base64,AAAA\"></p>"
+    );
+}
@@ -0,0 +1,141 @@
+use lib_ot::rich_text::RichTextDelta;
+
+#[test]
+fn markdown_export_inline_attributes() {
+    let delta = RichTextDelta::from_delta_str(
+        r#"[
+        {"insert":"Hello "},
+        {"insert":"bold","attributes":{"bold":true}},
+        {"insert":" and "},
+        {"insert":"italic","attributes":{"italic":true}},
+        {"insert":", "},
+        {"insert":"code","attributes":{"code":true}},
+        {"insert":", "},
+        {"insert":"AppFlowy","attributes":{"link":"https://appflowy.io"}},
+        {"insert":"\n"}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        delta.to_markdown(),
+        "Hello **bold** and *italic*, `code`, [AppFlowy](https://appflowy.io)\n"
+    );
+}
+
+#[test]
+fn markdown_export_block_attributes() {
+    let delta = RichTextDelta::from_delta_str(
+        r#"[
+        {"insert":"Title"},
+        {"insert":"\n","attributes":{"header":1}},
+        {"insert":"first"},
+        {"insert":"\n","attributes":{"list":"ordered"}},
+        {"insert":"second"},
+        {"insert":"\n","attributes":{"list":"ordered"}},
+        {"insert":"item"},
+        {"insert":"\n","attributes":{"list":"bullet"}},
+        {"insert":"todo"},
+        {"insert":"\n","attributes":{"list":"unchecked"}},
+        {"insert":"quote"},
+        {"insert":"\n","attributes":{"blockquote":true}},
+        {"insert":"end"}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        delta.to_markdown(),
+        "# Title\n1. first\n2. second\n- item\n- [ ] todo\n> quote\nend"
+    );
+}
+
+#[test]
+fn markdown_export_escape_plain_text() {
+    let delta = RichTextDelta::from_delta_str(
+        r#"[
+        {"insert":"2*3 is_six `x` [a]\n"},
+        {"insert":"*kept*","attributes":{"code":true}}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(delta.to_markdown(), "2\\*3 is\\_six \\`x\\` \\[a\\]\n`*kept*`");
+}
+
+#[test]
+fn markdown_export_merge_adjacent_runs() {
+    let delta = RichTextDelta::from_delta_str(
+        r#"[
+        {"insert":"a","attributes":{"bold":true}},
+        {"insert":"b","attributes":{"bold":true,"color":"red"}},
+        {"insert":"c","attributes":{"bold":true,"italic":true}},
+        {"insert":" "},
+        {"insert":"App","attributes":{"link":"https://appflowy.io"}},
+        {"insert":"Flowy","attributes":{"link":"https://appflowy.io","bold":true}}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(delta.to_markdown(), "**ab*c*** [App**Flowy**](https://appflowy.io)");
+}
+
+#[test]
+fn markdown_export_escape_block_markers() {
+    let delta = RichTextDelta::from_delta_str(
+        r##"[{"insert":"# not header\n- not list\n> not quote\n1. not ordered\n+ plus\na - b # c 1. d"}]"##,
+    )
+    .unwrap();
+    let markdown = delta.to_markdown();
+    assert_eq!(
+        markdown,
+        "\\# not header\n\\- not list\n\\> not quote\n1\\. not ordered\n\\+ plus\na - b # c 1. d"
+    );
+    assert_eq!(RichTextDelta::from_markdown(&markdown), delta);
+}
+
+#[test]
+fn markdown_export_code_with_backticks() {
+    let delta = RichTextDelta::from_delta_str(
+        r#"[
+        {"insert":"a`b","attributes":{"code":true}},
+        {"insert":" "},
+        {"insert":"``x``","attributes":{"code":true}}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(delta.to_markdown(), "``a`b`` ``` ``x`` ```");
+}
+
+#[test]
+fn markdown_import_round_trip() {
+    let delta = RichTextDelta::from_delta_str(
+        r#"[
+        {"insert":"Title"},
+        {"insert":"\n","attributes":{"header":2}},
+        {"insert":"Hello "},
+        {"insert":"bold","attributes":{"bold":true}},
+        {"insert":" "},
+        {"insert":"both","attributes":{"bold":true,"italic":true}},
+        {"insert":" 2*3 "},
+        {"insert":"code","attributes":{"code":true}},
+        {"insert":" "},
+        {"insert":"AppFlowy","attributes":{"link":"https://appflowy.io"}},
+        {"insert":"\n"},
+        {"insert":"first"},
+        {"insert":"\n","attributes":{"list":"ordered"}},
+        {"insert":"second"},
+        {"insert":"\n","attributes":{"list":"ordered"}},
+        {"insert":"item"},
+        {"insert":"\n","attributes":{"list":"bullet"}},
+        {"insert":"done"},
+        {"insert":"\n","attributes":{"list":"checked"}}
+        ]"#,
+    )
+    .unwrap();
+    let markdown = delta.to_markdown();
+    assert_eq!(RichTextDelta::from_markdown(&markdown), delta);
+}
+
+#[test]
+fn markdown_import_malformed_syntax_as_text() {
+    let delta = RichTextDelta::from_markdown("**not closed [link](missing\n#not header");
+    let expected = RichTextDelta::from_delta_str(r#"[{"insert":"**not closed [link](missing\n#not header"}]"#).unwrap();
+    assert_eq!(delta, expected);
+}
@@ -1,5 +1,8 @@
 #![allow(clippy::module_inception)]
 mod attribute_test;
+mod convergence_test;
+mod html_test;
+mod markdown_test;
 mod op_test;
 mod serde_test;
 mod undo_redo_test;
@@ -284,7 +287,6 @@ impl Default for Rng {
 }
 
 impl Rng {
-    #[allow(dead_code)]
     pub fn from_seed(seed: [u8; 32]) -> Self {
         Rng(StdRng::from_seed(seed))
     }
@@ -299,6 +301,58 @@ impl Rng {
     }
 
     pub fn gen_delta(&mut self, s: &str) -> RichTextDelta {
+        self.gen_delta_with_attributes(s, false)
+    }
+
+    // Generates the delta whose inserts may be bold and whose retains may bold or unbold the text.
+    pub fn gen_formatted_delta(&mut self, s: &str) -> RichTextDelta {
+        self.gen_delta_with_attributes(s, true)
+    }
+
+    // Generates the delta that only formats the text of the given length, i.e. the retains that
+    // may bold or unbold the text.
+    pub fn gen_format_delta(&mut self, len: usize) -> RichTextDelta {
+        let mut delta = RichTextDelta::default();
+        while delta.utf16_base_len < len {
+            let left = len - delta.utf16_base_len;
+            let i = 1 + self.0.gen_range(0..std::cmp::min(left, 20));
+            let attributes = self.gen_attributes(true, true);
+            delta.retain(i, attributes);
+        }
+        delta
+    }
+
+    // Generates the small edit at a random position of the text of the given length, like the one
+    // made by typing: the insert of a few chars, or the delete or the format of up to 5 chars.
+    pub fn gen_local_edit(&mut self, len: usize) -> RichTextDelta {
+        let index = self.0.gen_range(0..=len);
+        let n = std::cmp::min(1 + self.0.gen_range(0..5), len - index);
+        let mut delta = RichTextDelta::default();
+        delta.retain(index, RichTextAttributes::default());
+        match self.0.gen_range(0..3) {
+            1 if n > 0 => delta.delete(n),
+            2 if n > 0 => {
+                let attributes = RichTextAttribute::Bold(self.0.gen_bool(0.5)).into();
+                delta.retain(n, attributes);
+            }
+            _ => {
+                let attributes = self.gen_attributes(true, false);
+                delta.insert(&self.gen_string(1), attributes);
+            }
+        }
+        delta.retain(len - delta.utf16_base_len, RichTextAttributes::default());
+        delta
+    }
+
+    fn gen_attributes(&mut self, formatted: bool, removal: bool) -> RichTextAttributes {
+        if !formatted || self.0.gen_bool(0.5) {
+            return RichTextAttributes::default();
+        }
+        let bold = !removal || self.0.gen_bool(0.5);
+        RichTextAttribute::Bold(bold).into()
+    }
+
+    fn gen_delta_with_attributes(&mut self, s: &str, formatted: bool) -> RichTextDelta {
         let mut delta = RichTextDelta::default();
         let s = FlowyStr::from(s);
         loop {
@@ -313,13 +367,15 @@ impl Rng {
             };
             match self.0.gen_range(0.0..1.0) {
                 f if f < 0.2 => {
-                    delta.insert(&self.gen_string(i), RichTextAttributes::default());
+                    let attributes = self.gen_attributes(formatted, false);
+                    delta.insert(&self.gen_string(i), attributes);
                 }
                 f if f < 0.4 => {
                     delta.delete(i);
                 }
                 _ => {
-                    delta.retain(i, RichTextAttributes::default());
+                    let attributes = self.gen_attributes(formatted, true);
+                    delta.retain(i, attributes);
                 }
             }
         }
@@ -3,8 +3,12 @@ use crate::editor::{Rng, TestBuilder, TestOp::*};
 use flowy_sync::client_document::{NewlineDoc, PlainDoc};
 use lib_ot::{
     core::*,
-    rich_text::{AttributeBuilder, RichTextAttribute, RichTextAttributes, RichTextDelta},
+    errors::OTErrorCode,
+    rich_text::{
+        AttributeBuilder, RichTextAttribute, RichTextAttributeKey, RichTextAttributes, RichTextDelta, RichTextOperation,
+    },
 };
+use rand::{thread_rng, Rng as WrappedRng};
 
 #[test]
 fn attributes_insert_text() {
@@ -393,6 +397,36 @@ fn empty_ops() {
     assert_eq!(delta.ops.len(), 0);
 }
 #[test]
+fn empty_ops_with_attributes() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut delta = RichTextDelta::default();
+    delta.retain(0, bold.clone());
+    delta.insert("", bold.clone());
+    delta.add(OpBuilder::retain_with_attributes(0, bold.clone()).build());
+    delta.add(OpBuilder::insert_with_attributes("", bold.clone()).build());
+    delta.add(OpBuilder::delete(0).build());
+    assert!(delta.is_empty());
+    assert!(delta.validate().is_ok());
+
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .retain(1)
+        .retain_with_attributes(0, bold.clone())
+        .insert_with_attributes("", bold)
+        .delete(0)
+        .retain(1)
+        .build();
+    assert_eq!(delta.ops, vec![OpBuilder::retain(2).build()]);
+
+    // The empty operations pushed directly are dropped by normalize.
+    let mut delta = RichTextDelta::default();
+    delta.ops.push(OpBuilder::retain(0).build());
+    delta.ops.push(OpBuilder::insert("").build());
+    delta.ops.push(OpBuilder::delete(0).build());
+    assert!(delta.validate().is_err());
+    delta.normalize();
+    assert!(delta.is_empty());
+}
+#[test]
 fn eq() {
     let mut delta_a = RichTextDelta::default();
     delta_a.delete(1);
@@ -443,6 +477,35 @@ fn is_noop() {
     delta.insert("lorem", RichTextAttributes::default());
     assert!(!delta.is_noop());
 }
+#[test]
+fn is_noop_with_attributes_or_delete() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let delta: RichTextDelta = DeltaBuilder::new().retain(2).retain_with_attributes(3, bold).build();
+    assert!(!delta.is_noop());
+
+    let delta: RichTextDelta = DeltaBuilder::new().retain(2).delete(3).build();
+    assert!(!delta.is_noop());
+}
+
+#[test]
+fn operation_is_noop() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let plain_retain: RichTextOperation = OpBuilder::retain(2).build();
+    assert!(plain_retain.is_retain());
+    assert!(plain_retain.is_noop());
+
+    let bold_retain: RichTextOperation = OpBuilder::retain(2).attributes(bold).build();
+    assert!(bold_retain.is_retain());
+    assert!(!bold_retain.is_noop());
+
+    let insert: RichTextOperation = OpBuilder::insert("12").build();
+    assert!(insert.is_insert());
+    assert!(!insert.is_noop());
+
+    let delete: RichTextOperation = OpBuilder::delete(2).build();
+    assert!(!delete.is_noop());
+}
+
 #[test]
 fn compose() {
     for _ in 0..1000 {
@@ -749,3 +812,2076 @@ fn delta_compose_with_missing_delta() {
     ];
     TestBuilder::new().run_scripts::<NewlineDoc>(ops);
 }
+
+#[test]
+fn operation_transform_insert_at_same_position() {
+    let a = OpBuilder::<RichTextAttributes>::insert("123").build();
+    let b = OpBuilder::<RichTextAttributes>::insert("456").build();
+    let delta_a: RichTextDelta = vec![a.clone()].into_iter().collect();
+    let delta_b: RichTextDelta = vec![b.clone()].into_iter().collect();
+
+    let (a_prime, b_prime) = a.transform(&b, true);
+    assert_eq!(delta_a.compose(&b_prime).unwrap(), delta_b.compose(&a_prime).unwrap());
+    assert_eq!(delta_a.compose(&b_prime).unwrap().apply("").unwrap(), "123456");
+
+    let (a_prime, b_prime) = a.transform(&b, false);
+    assert_eq!(delta_a.compose(&b_prime).unwrap(), delta_b.compose(&a_prime).unwrap());
+    assert_eq!(delta_a.compose(&b_prime).unwrap().apply("").unwrap(), "456123");
+}
+
+#[test]
+fn operation_transform_insert_with_overlapping_delete() {
+    let s = "xyz";
+    let a = OpBuilder::<RichTextAttributes>::insert("ab").build();
+    let b = OpBuilder::<RichTextAttributes>::delete(3).build();
+    let (a_prime, b_prime) = a.transform(&b, true);
+
+    let delta_a: RichTextDelta = DeltaBuilder::new().insert("ab").retain(3).build();
+    let delta_b: RichTextDelta = DeltaBuilder::new().delete(3).build();
+    assert_eq!(a_prime.apply(&delta_b.apply(s).unwrap()).unwrap(), "ab");
+    assert_eq!(b_prime.apply(&delta_a.apply(s).unwrap()).unwrap(), "ab");
+}
+
+#[test]
+fn insert_lengths_with_multi_byte_characters() {
+    let insert = lib_ot::core::Insert::<RichTextAttributes>::from("😀你a");
+    assert_eq!(insert.s.chars().count(), 3);
+    assert_eq!(insert.num_bytes(), 8);
+    assert_eq!(insert.num_utf16_units(), 4);
+    assert_eq!(insert.utf16_size(), 4);
+
+    // The CJK characters take three bytes but one UTF-16 code unit each.
+    let insert = lib_ot::core::Insert::<RichTextAttributes>::from("中文👋");
+    assert_eq!(insert.s.chars().count(), 3);
+    assert_eq!(insert.num_bytes(), 10);
+    assert_eq!(insert.num_utf16_units(), 4);
+}
+
+#[test]
+fn operation_split_insert_with_attributes() {
+    let attributes = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let insert = OpBuilder::insert("héllo").attributes(attributes.clone()).build();
+    let (left, right) = insert.split(2);
+    assert_eq!(
+        left.unwrap(),
+        OpBuilder::insert("hé").attributes(attributes.clone()).build()
+    );
+    assert_eq!(right.unwrap(), OpBuilder::insert("llo").attributes(attributes).build());
+}
+
+#[test]
+fn operation_split_retain_and_delete() {
+    let attributes = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Italic(true))
+        .build();
+    let retain = OpBuilder::retain(10).attributes(attributes.clone()).build();
+    let (left, right) = retain.split(4);
+    assert_eq!(
+        left.unwrap(),
+        OpBuilder::retain(4).attributes(attributes.clone()).build()
+    );
+    assert_eq!(right.unwrap(), OpBuilder::retain(6).attributes(attributes).build());
+
+    let delete = OpBuilder::<RichTextAttributes>::delete(3).build();
+    assert_eq!(delete.split(0), (None, Some(delete.clone())));
+    assert_eq!(delete.split(3), (Some(delete.clone()), None));
+}
+
+#[test]
+fn delta_base_and_target_len() {
+    let delta: RichTextDelta = DeltaBuilder::new().retain(3).insert("😀").delete(2).build();
+    assert_eq!(delta.base_len(), 5);
+    assert_eq!(delta.target_len(), 5);
+    assert_eq!(delta.base_len(), delta.utf16_base_len);
+    assert_eq!(delta.target_len(), delta.utf16_target_len);
+}
+
+#[test]
+fn delta_compose_delete_cancels_insert() {
+    let a: RichTextDelta = DeltaBuilder::new().insert("abc").build();
+    let b: RichTextDelta = DeltaBuilder::new().retain(1).delete(2).build();
+    let ab = a.compose(&b).unwrap();
+    assert_eq!(ab, DeltaBuilder::new().insert("a").build());
+    assert_eq!(ab.apply("").unwrap(), b.apply(&a.apply("").unwrap()).unwrap());
+}
+
+#[test]
+fn delta_compose_retain_attributes_over_insert() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let a: RichTextDelta = DeltaBuilder::new().insert("abc").build();
+    let b: RichTextDelta = DeltaBuilder::new()
+        .retain(1)
+        .retain_with_attributes(2, bold.clone())
+        .build();
+    let ab = a.compose(&b).unwrap();
+    assert_eq!(
+        ab,
+        DeltaBuilder::new()
+            .insert("a")
+            .insert_with_attributes("bc", bold)
+            .build()
+    );
+}
+
+#[test]
+fn apply_with_astral_plane_characters() {
+    let s = "😀a👋";
+    let delta: RichTextDelta = DeltaBuilder::new().retain(2).insert("b").delete(1).retain(2).build();
+    assert_eq!(delta.apply(s).unwrap(), "😀b👋");
+
+    let delta: RichTextDelta = DeltaBuilder::new().retain(4).build();
+    assert!(delta.apply(s).is_err());
+}
+
+#[test]
+fn invert_str_with_astral_plane_characters() {
+    let s = "😀a👋";
+    let delta: RichTextDelta = DeltaBuilder::new().retain(2).delete(3).insert("b").build();
+    let undo = delta.invert_str(s);
+    assert_eq!(undo.apply(&delta.apply(s).unwrap()).unwrap(), s);
+}
+
+#[test]
+fn invert_unbold_restores_bold() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut unbold = RichTextAttributes::new();
+    unbold.delete(&RichTextAttributeKey::Bold);
+
+    let document: RichTextDelta = DeltaBuilder::new().insert_with_attributes("abc", bold).build();
+    let change: RichTextDelta = DeltaBuilder::new().retain_with_attributes(3, unbold).build();
+    let undo = change.invert(&document);
+
+    let new_document = document.compose(&change).unwrap();
+    assert_eq!(new_document, DeltaBuilder::new().insert("abc").build());
+    assert_eq!(new_document.compose(&undo).unwrap(), document);
+}
+
+#[test]
+fn delta_with_embed() {
+    let image = Embed::new("image", "https://appflowy.io/logo.png");
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .insert("a")
+        .insert_embed(image.clone(), RichTextAttributes::default())
+        .insert("b")
+        .build();
+    assert_eq!(delta.ops.len(), 3);
+    assert_eq!(delta.target_len(), 3);
+
+    let change: RichTextDelta = DeltaBuilder::new().retain(1).delete(1).build();
+    let composed = delta.compose(&change).unwrap();
+    assert_eq!(composed, DeltaBuilder::new().insert("ab").build());
+}
+
+#[test]
+fn delta_chop_trailing_plain_retain() {
+    let mut delta: RichTextDelta = DeltaBuilder::new().insert("a").retain(5).build();
+    delta.chop();
+    assert_eq!(delta.ops, vec![OpBuilder::insert("a").build()]);
+    assert_eq!(delta.base_len(), 0);
+    assert_eq!(delta.target_len(), 1);
+    assert!(delta.validate().is_ok());
+    assert_eq!(delta.apply("").unwrap(), "a");
+}
+
+#[test]
+fn delta_compose_keeps_trailing_plain_retain() {
+    let a: RichTextDelta = DeltaBuilder::new().retain(3).insert("x").retain(2).build();
+    let b: RichTextDelta = DeltaBuilder::new().insert("y").retain(6).build();
+    let composed = a.compose(&b).unwrap();
+    assert_eq!(
+        composed,
+        DeltaBuilder::new().insert("y").retain(3).insert("x").retain(2).build()
+    );
+
+    let json = RichTextDelta::from_delta_str(&composed.to_delta_str()).unwrap();
+    let bytes = RichTextDelta::from_bytes(composed.to_delta_bytes()).unwrap();
+    let compact = RichTextDelta::from_compact_bytes(&composed.to_compact_bytes()).unwrap();
+    for delta in [json, bytes, compact] {
+        assert_eq!(delta, composed);
+        assert_eq!(delta.apply("hello").unwrap(), "yhelxlo");
+    }
+}
+
+#[test]
+fn delta_chopped_covers_the_beginning() {
+    let mut rng = Rng::default();
+    for _ in 0..1000 {
+        let s = rng.gen_string(20);
+        let a = rng.gen_delta(&s);
+        let mut chopped_a = a.clone();
+        chopped_a.chop();
+        assert!(chopped_a.validate().is_ok());
+        let (head, tail) = s.split_at(chopped_a.base_len());
+        assert_eq!(chopped_a.apply(head).unwrap() + tail, a.apply(&s).unwrap());
+
+        // The document keeps the tail the chopped delta doesn't cover.
+        let document: RichTextDelta = DeltaBuilder::new().insert(&s).build();
+        assert_eq!(document.compose(&chopped_a).unwrap(), document.compose(&a).unwrap());
+    }
+}
+
+#[test]
+fn delta_chop_keeps_retain_with_attributes() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut delta: RichTextDelta = DeltaBuilder::new().insert("a").retain_with_attributes(5, bold).build();
+    let expected = delta.clone();
+    delta.chop();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn delta_semantic_eq() {
+    let a: RichTextDelta = DeltaBuilder::new().insert("a").build();
+    let b: RichTextDelta = DeltaBuilder::new().insert("a").retain(5).build();
+    assert!(a.semantic_eq(&b));
+    assert!(b.semantic_eq(&a));
+    assert_ne!(a, b);
+
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let c: RichTextDelta = DeltaBuilder::new().insert("a").retain_with_attributes(5, bold).build();
+    assert!(!a.semantic_eq(&c));
+    assert!(!a.semantic_eq(&DeltaBuilder::new().insert("b").build()));
+}
+
+#[test]
+fn delta_transform_index() {
+    let insert: RichTextDelta = DeltaBuilder::new().retain(2).insert("abc").build();
+    assert_eq!(insert.transform_index(5, false), 8);
+    assert_eq!(insert.transform_index(1, false), 1);
+
+    let delete: RichTextDelta = DeltaBuilder::new().delete(2).build();
+    assert_eq!(delete.transform_index(5, false), 3);
+    assert_eq!(delete.transform_index(1, false), 0);
+}
+
+#[test]
+fn delta_transform_index_with_priority() {
+    let delta: RichTextDelta = DeltaBuilder::new().retain(2).insert("abc").build();
+    assert_eq!(delta.transform_index(2, true), 2);
+    assert_eq!(delta.transform_index(2, false), 5);
+}
+
+#[test]
+fn op_builder_with_attributes() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    assert_eq!(
+        OpBuilder::insert_with_attributes("123", bold.clone()).build(),
+        OpBuilder::insert("123").attributes(bold.clone()).build()
+    );
+    assert_eq!(
+        OpBuilder::retain_with_attributes(3, bold.clone()).build(),
+        OpBuilder::retain(3).attributes(bold).build()
+    );
+    assert!(OpBuilder::insert_with_attributes("123", RichTextAttributes::default())
+        .build()
+        .is_plain());
+}
+
+#[test]
+fn delta_normalize() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut delta = RichTextDelta::default();
+    delta.ops.push(OpBuilder::retain(1).build());
+    delta.ops.push(OpBuilder::retain(2).build());
+    delta.ops.push(OpBuilder::retain(3).attributes(bold.clone()).build());
+    delta.ops.push(OpBuilder::delete(2).build());
+    delta.ops.push(OpBuilder::insert("a").build());
+    delta.ops.push(OpBuilder::insert("b").build());
+    delta.ops.push(OpBuilder::delete(3).build());
+    delta.ops.push(OpBuilder::insert("").build());
+    delta.normalize();
+
+    let expected: RichTextDelta = DeltaBuilder::new()
+        .retain(3)
+        .retain_with_attributes(3, bold)
+        .insert("ab")
+        .delete(5)
+        .build();
+    assert_eq!(delta, expected);
+    assert_eq!(delta.base_len(), 11);
+    assert_eq!(delta.target_len(), 8);
+}
+
+#[test]
+fn operation_merge_run() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let ops: Vec<RichTextOperation> = vec![
+        OpBuilder::retain(1).build(),
+        OpBuilder::retain(2).build(),
+        OpBuilder::delete(1).build(),
+        OpBuilder::insert("a").attributes(bold.clone()).build(),
+        OpBuilder::insert("b").attributes(bold.clone()).build(),
+        OpBuilder::delete(2).build(),
+        OpBuilder::retain(0).build(),
+        OpBuilder::insert("c").build(),
+    ];
+    let merged = Operation::merge_run(&ops);
+    assert_eq!(
+        merged,
+        vec![
+            OpBuilder::retain(3).build(),
+            OpBuilder::insert("ab").attributes(bold).build(),
+            OpBuilder::insert("c").build(),
+            OpBuilder::delete(3).build(),
+        ]
+    );
+
+    let mut delta = RichTextDelta::default();
+    delta.ops = ops;
+    delta.normalize();
+    assert_eq!(merged, delta.ops);
+}
+
+#[test]
+fn delta_compact() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut delta = RichTextDelta::default();
+    for _ in 0..100 {
+        delta.ops.push(OpBuilder::insert("a").attributes(bold.clone()).build());
+    }
+    delta.ops.push(OpBuilder::insert("b").build());
+    delta.ops.push(OpBuilder::retain(0).build());
+    let mut normalized = delta.clone();
+    normalized.normalize();
+
+    delta.compact();
+    assert_eq!(delta, normalized);
+    assert_eq!(delta.ops.len(), 2);
+    assert_eq!(delta.ops.capacity(), 2);
+    match &delta.ops[0] {
+        Operation::Insert(insert) => assert_eq!(insert.s.capacity(), 100),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn delta_compact_interns_attributes() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut delta = RichTextDelta::default();
+    for _ in 0..100 {
+        delta.insert("a", bold.clone());
+        delta.insert("b", RichTextAttributes::default());
+    }
+    delta.retain(3, bold.clone());
+    assert!(delta.ops.iter().all(|op| !op.attributes_ref().unwrap().is_shared()));
+    let expected = delta.clone();
+
+    delta.compact();
+    assert_eq!(delta, expected);
+    assert_eq!(delta.to_delta_str(), expected.to_delta_str());
+    // The bold runs share one map, the plain ones have nothing to share.
+    for op in &delta.ops {
+        let attributes = op.attributes_ref().unwrap();
+        assert_eq!(attributes.is_shared(), attributes == &bold);
+    }
+
+    // Changing the shared attributes of one operation copies them.
+    let mut attributes = delta.ops[0].get_attributes();
+    attributes.add(RichTextAttribute::Italic(true));
+    assert!(!attributes.is_shared());
+    assert_eq!(delta.ops[0].get_attributes(), bold);
+}
+
+#[test]
+fn delta_split_at_newline() {
+    let header: RichTextAttributes = RichTextAttribute::Header(1).into();
+    let document: RichTextDelta = DeltaBuilder::new()
+        .insert("a")
+        .insert_with_attributes("\n", header.clone())
+        .insert("b\nc\n")
+        .build();
+    let (first, rest) = document.split_at_newline(1);
+    assert_eq!(
+        first,
+        DeltaBuilder::new()
+            .insert("a")
+            .insert_with_attributes("\n", header)
+            .build()
+    );
+    assert_eq!(rest, DeltaBuilder::new().insert("b\nc\n").build());
+    assert_eq!(first.concat(&rest), document);
+
+    let (first, rest) = document.split_at_newline(0);
+    assert!(first.is_empty());
+    assert_eq!(rest, document);
+    let (first, rest) = document.split_at_newline(3);
+    assert_eq!(first, document);
+    assert!(rest.is_empty());
+    assert_eq!(
+        document.split_at_newline(4),
+        (document.clone(), RichTextDelta::default())
+    );
+
+    // The line without the trailing newline is in the second half unless the index is beyond it.
+    let document: RichTextDelta = DeltaBuilder::new().insert("a\nb").build();
+    let (first, rest) = document.split_at_newline(1);
+    assert_eq!(first.plain_text(), "a\n");
+    assert_eq!(rest.plain_text(), "b");
+    assert_eq!(document.split_at_newline(2).0, document);
+}
+
+#[test]
+fn delta_take_prefix() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut delta: RichTextDelta = DeltaBuilder::new()
+        .insert("ab")
+        .insert_with_attributes("cdef", bold.clone())
+        .build();
+    let prefix = delta.take_prefix(3);
+    assert_eq!(
+        prefix,
+        DeltaBuilder::new()
+            .insert("ab")
+            .insert_with_attributes("c", bold.clone())
+            .build()
+    );
+    assert_eq!(delta, DeltaBuilder::new().insert_with_attributes("def", bold).build());
+    assert_eq!(prefix.concat(&delta).apply("").unwrap(), "abcdef");
+
+    // The deletes don't produce any output, the ones before the cut go to the prefix.
+    let mut delta: RichTextDelta = DeltaBuilder::new().delete(2).retain(1).insert("xyz").build();
+    let prefix = delta.take_prefix(3);
+    assert_eq!(prefix, DeltaBuilder::new().delete(2).retain(1).insert("xy").build());
+    assert_eq!(delta, DeltaBuilder::new().insert("z").build());
+}
+
+#[test]
+fn delta_slice() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .insert("abc")
+        .retain_with_attributes(2, bold.clone())
+        .delete(3)
+        .build();
+
+    let slice = delta.slice(2, 6);
+    assert_eq!(
+        slice,
+        DeltaBuilder::new()
+            .insert("c")
+            .retain_with_attributes(2, bold)
+            .delete(1)
+            .build()
+    );
+    let len = |delta: &RichTextDelta| delta.ops.iter().map(|op| op.len()).sum::<usize>();
+    assert_eq!(len(&slice), 4);
+    assert_eq!(len(&delta.slice(0, 5)) + len(&delta.slice(5, 8)), len(&delta));
+    assert!(delta.slice(6, 2).is_empty());
+}
+
+#[test]
+fn delta_diff() {
+    let delta = RichTextDelta::diff("hello world", "hello appflowy");
+    assert_eq!(delta.apply("hello world").unwrap(), "hello appflowy");
+
+    let delta = RichTextDelta::diff("你好😁", "你😁好");
+    assert_eq!(delta.apply("你好😁").unwrap(), "你😁好");
+
+    let delta = RichTextDelta::diff("abc", "abc");
+    assert_eq!(delta, DeltaBuilder::new().retain(3).build());
+    assert!(delta.is_noop());
+}
+
+#[test]
+fn delta_diff_with_cursor() {
+    // Typing `a` after the second `a`, the plain diff may put the insert anywhere.
+    let (delta, cursor) = RichTextDelta::diff_with_cursor("aaa", "aaaa", 2);
+    assert_eq!(delta, DeltaBuilder::new().retain(2).insert("a").retain(1).build());
+    assert_eq!(cursor, 3);
+
+    // The backspace and the forward delete at the caret.
+    let (delta, cursor) = RichTextDelta::diff_with_cursor("abbc", "abc", 3);
+    assert_eq!(delta, DeltaBuilder::new().retain(2).delete(1).retain(1).build());
+    assert_eq!(cursor, 2);
+    let (delta, cursor) = RichTextDelta::diff_with_cursor("abbc", "abc", 1);
+    assert_eq!(delta, DeltaBuilder::new().retain(1).delete(1).retain(2).build());
+    assert_eq!(cursor, 1);
+
+    // The emoji takes two code units.
+    let (delta, cursor) = RichTextDelta::diff_with_cursor("😁😁", "😁😁😁", 2);
+    assert_eq!(delta, DeltaBuilder::new().retain(2).insert("😁").retain(2).build());
+    assert_eq!(cursor, 4);
+
+    // The other changes fall back to the diff.
+    let (delta, cursor) = RichTextDelta::diff_with_cursor("hello world", "hello appflowy", 11);
+    assert_eq!(delta.apply("hello world").unwrap(), "hello appflowy");
+    assert_eq!(cursor, 14);
+}
+
+#[test]
+fn delta_diff_random_string() {
+    for _ in 0..1000 {
+        let mut rng = Rng::default();
+        let a = rng.gen_string(20);
+        let b = rng.gen_delta(&a).apply(&a).unwrap();
+        let delta = RichTextDelta::diff(&a, &b);
+        assert_eq!(delta.apply(&a).unwrap(), b);
+    }
+}
+
+#[test]
+fn delta_iter_chars() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let delta = DeltaBuilder::new()
+        .insert_with_attributes("ab", bold.clone())
+        .insert("c")
+        .build();
+
+    let chars = delta.iter_chars().collect::<Vec<_>>();
+    assert_eq!(chars, vec![('a', Some(bold.clone())), ('b', Some(bold)), ('c', None)]);
+}
+
+#[test]
+fn delta_builder_merges_operations() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .retain(1)
+        .retain(2)
+        .delete(1)
+        .insert("hi")
+        .insert_with_attributes("!", bold.clone())
+        .insert_with_attributes("!", bold.clone())
+        .build();
+
+    assert_eq!(
+        delta.ops,
+        vec![
+            OpBuilder::retain(3).build(),
+            OpBuilder::insert("hi").build(),
+            OpBuilder::insert_with_attributes("!!", bold).build(),
+            OpBuilder::delete(1).build(),
+        ]
+    );
+    assert_eq!(delta.base_len(), 4);
+    assert_eq!(delta.target_len(), 7);
+}
+
+#[test]
+fn op_builder_delete_without_attributes() {
+    let op = OpBuilder::<RichTextAttributes>::delete(3)
+        .attributes(RichTextAttributes::default())
+        .build();
+    assert_eq!(op, Operation::Delete(3));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn op_builder_delete_with_attributes() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let _ = OpBuilder::delete(3).attributes(bold).build();
+}
+
+#[test]
+fn operation_compose() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let insert = |s: &str| OpBuilder::<RichTextAttributes>::insert(s).build();
+    let bold_insert = |s: &str| OpBuilder::insert_with_attributes(s, bold.clone()).build();
+    let bold_retain = |n: usize| OpBuilder::retain_with_attributes(n, bold.clone()).build();
+
+    assert_eq!(insert("ab").compose(&insert("c")), Some(insert("abc")));
+    assert_eq!(bold_insert("ab").compose(&bold_insert("c")), Some(bold_insert("abc")));
+    assert_eq!(
+        OpBuilder::<RichTextAttributes>::delete(2)
+            .build()
+            .compose(&OpBuilder::delete(3).build()),
+        Some(OpBuilder::delete(5).build())
+    );
+    assert_eq!(
+        OpBuilder::<RichTextAttributes>::retain(2)
+            .build()
+            .compose(&OpBuilder::retain(3).build()),
+        Some(OpBuilder::retain(5).build())
+    );
+    assert_eq!(bold_retain(2).compose(&bold_retain(3)), Some(bold_retain(5)));
+
+    // The operations that can't be combined.
+    assert_eq!(insert("ab").compose(&bold_insert("c")), None);
+    assert_eq!(bold_retain(2).compose(&OpBuilder::retain(3).build()), None);
+    assert_eq!(insert("ab").compose(&OpBuilder::delete(1).build()), None);
+    assert_eq!(
+        OpBuilder::<RichTextAttributes>::delete(1)
+            .build()
+            .compose(&OpBuilder::retain(1).build()),
+        None
+    );
+    let embed = OpBuilder::<RichTextAttributes>::embed(Embed::new("image", "https://appflowy.io/logo.png")).build();
+    assert_eq!(embed.compose(&embed), None);
+}
+
+#[test]
+fn delta_each_line() {
+    let delta = RichTextDelta::from_delta_str(
+        r#"[
+        {"insert":"Title"},
+        {"insert":"\n","attributes":{"header":1}},
+        {"insert":"Hello "},
+        {"insert":"bold","attributes":{"bold":true}},
+        {"insert":"\nend\n"}
+        ]"#,
+    )
+    .unwrap();
+    let header = AttributeBuilder::new().add_attr(RichTextAttribute::Header(1)).build();
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+
+    let mut lines = vec![];
+    delta.each_line(|line, attributes| {
+        lines.push((line, attributes.clone()));
+        true
+    });
+    assert_eq!(
+        lines,
+        vec![
+            (DeltaBuilder::new().insert("Title").build(), header),
+            (
+                DeltaBuilder::new()
+                    .insert("Hello ")
+                    .insert_with_attributes("bold", bold)
+                    .build(),
+                RichTextAttributes::default()
+            ),
+            (DeltaBuilder::new().insert("end").build(), RichTextAttributes::default()),
+            (RichTextDelta::default(), RichTextAttributes::default()),
+        ]
+    );
+}
+
+#[test]
+fn delta_each_line_without_newline() {
+    let delta: RichTextDelta = DeltaBuilder::new().insert("abc").build();
+    let mut lines = vec![];
+    delta.each_line(|line, _| {
+        lines.push(line);
+        true
+    });
+    assert_eq!(lines, vec![delta.clone()]);
+}
+
+#[test]
+fn delta_each_line_stop_early() {
+    let delta: RichTextDelta = DeltaBuilder::new().insert("a\nb\nc").build();
+    let mut count = 0;
+    delta.each_line(|_, _| {
+        count += 1;
+        count < 2
+    });
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn delta_utf16_lengths_with_astral_plane_characters() {
+    // 😀 is one char but two UTF-16 code units, the same as "😀".length in JavaScript.
+    assert_eq!("😀".chars().count(), 1);
+    let delta: RichTextDelta = DeltaBuilder::new().retain(1).insert("😀").delete(1).build();
+    assert_eq!(delta.base_len(), 2);
+    assert_eq!(delta.target_len(), 3);
+
+    // The cursor after "a" in "ab" moves behind the emoji.
+    assert_eq!(delta.transform_index(1, false), 3);
+    assert_eq!(delta.transform_index(1, true), 1);
+    // The cursor after "b" moves to the end of "a😀".
+    assert_eq!(delta.transform_index(2, false), 3);
+    assert_eq!(delta.apply("ab").unwrap(), "a😀");
+}
+
+#[test]
+fn apply_rope() {
+    let s = "hello 😀 world";
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .retain(6)
+        .delete(2)
+        .insert("🎉 ")
+        .retain(6)
+        .insert("!")
+        .build();
+    let rope = ropey::Rope::from_str(s);
+    let new_rope = delta.apply_rope(&rope).unwrap();
+    assert_eq!(new_rope.to_string(), delta.apply(s).unwrap());
+    assert_eq!(new_rope.to_string(), "hello 🎉  world!");
+    assert_eq!(rope.to_string(), s);
+
+    assert!(delta.apply_rope(&ropey::Rope::from_str("hello")).is_err());
+}
+
+#[test]
+fn delta_transform_with_priority() {
+    let a: RichTextDelta = DeltaBuilder::new().insert("A").retain(3).build();
+    let b: RichTextDelta = DeltaBuilder::new().insert("B").retain(3).build();
+
+    let b_prime = a.transform_with_priority(&b, true).unwrap();
+    assert_eq!(a.compose(&b_prime).unwrap().apply("123").unwrap(), "AB123");
+    let b_prime = a.transform_with_priority(&b, false).unwrap();
+    assert_eq!(a.compose(&b_prime).unwrap().apply("123").unwrap(), "BA123");
+}
+
+#[test]
+fn delta_transform_with_priority_random_delta() {
+    for _ in 0..1000 {
+        let mut rng = Rng::default();
+        let s = rng.gen_string(20);
+        let a = rng.gen_delta(&s);
+        let b = rng.gen_delta(&s);
+        let ab_prime = a.compose(&a.transform_with_priority(&b, true).unwrap()).unwrap();
+        let ba_prime = b.compose(&b.transform_with_priority(&a, false).unwrap()).unwrap();
+        assert_eq!(ab_prime, ba_prime);
+        assert_eq!(ab_prime.apply(&s).unwrap(), ba_prime.apply(&s).unwrap());
+    }
+}
+
+#[test]
+fn delta_transform_concurrent_format() {
+    let red = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Color("red".to_owned()))
+        .build();
+    let blue = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Color("blue".to_owned()))
+        .build();
+    let document: RichTextDelta = DeltaBuilder::new().insert("123456").build();
+    let a: RichTextDelta = DeltaBuilder::new()
+        .retain_with_attributes(4, red.clone())
+        .retain(2)
+        .build();
+    let b: RichTextDelta = DeltaBuilder::new()
+        .retain(2)
+        .retain_with_attributes(4, blue.clone())
+        .build();
+
+    let (a_prime, b_prime) = a.transform(&b).unwrap();
+    let ab_prime = document.compose(&a).unwrap().compose(&b_prime).unwrap();
+    let ba_prime = document.compose(&b).unwrap().compose(&a_prime).unwrap();
+    assert_eq!(ab_prime, ba_prime);
+
+    // The overlapping range takes the color of a, which has the priority.
+    let expected: RichTextDelta = DeltaBuilder::new()
+        .insert_with_attributes("1234", red)
+        .insert_with_attributes("56", blue)
+        .build();
+    assert_eq!(ab_prime, expected);
+}
+
+#[test]
+fn delta_compose_many() {
+    let a: RichTextDelta = DeltaBuilder::new().insert("123").build();
+    let b: RichTextDelta = DeltaBuilder::new().retain(1).delete(1).insert("x").retain(1).build();
+    let c: RichTextDelta = DeltaBuilder::new().retain(3).insert("456").build();
+
+    let composed = RichTextDelta::compose_many(&[a.clone(), b.clone(), c.clone()]).unwrap();
+    assert_eq!(composed, a.compose(&b).unwrap().compose(&c).unwrap());
+    assert_eq!(composed.apply("").unwrap(), "1x3456");
+
+    assert_eq!(RichTextDelta::compose_many(&[a.clone()]).unwrap(), a);
+    assert_eq!(RichTextDelta::compose_many(&[]).unwrap(), RichTextDelta::default());
+}
+
+#[test]
+fn delta_validate() {
+    let delta: RichTextDelta = DeltaBuilder::new().retain(2).insert("abc").delete(1).build();
+    assert!(delta.validate().is_ok());
+    assert!(RichTextDelta::default().validate().is_ok());
+
+    let mut zero_retain = delta.clone();
+    zero_retain.ops.insert(1, OpBuilder::retain(0).build());
+    let err = zero_retain.validate().unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::InvalidOperation));
+    assert!(err.msg.contains("at 1"));
+
+    let mut zero_delete = delta.clone();
+    zero_delete.ops.push(OpBuilder::delete(0).build());
+    assert!(matches!(
+        zero_delete.validate().unwrap_err().code,
+        OTErrorCode::InvalidOperation
+    ));
+
+    let mut empty_insert = delta.clone();
+    empty_insert.ops.insert(0, OpBuilder::insert("").build());
+    let err = empty_insert.validate().unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::InvalidOperation));
+    assert!(err.msg.contains("at 0"));
+
+    let mut wrong_len = delta.clone();
+    wrong_len.utf16_target_len += 1;
+    assert!(matches!(
+        wrong_len.validate().unwrap_err().code,
+        OTErrorCode::IncompatibleLength
+    ));
+
+    let mut wrong_len = delta;
+    wrong_len.utf16_base_len -= 1;
+    assert!(matches!(
+        wrong_len.validate().unwrap_err().code,
+        OTErrorCode::IncompatibleLength
+    ));
+}
+
+#[test]
+fn delta_concat() {
+    let foo: RichTextDelta = DeltaBuilder::new().insert("foo").build();
+    let bar: RichTextDelta = DeltaBuilder::new().insert("bar").build();
+    let delta = foo.concat(&bar);
+    assert_eq!(delta.ops, vec![OpBuilder::insert("foobar").build()]);
+    assert_eq!(delta.target_len(), 6);
+
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let a: RichTextDelta = DeltaBuilder::new().retain(2).delete(1).build();
+    let b: RichTextDelta = DeltaBuilder::new()
+        .delete(2)
+        .retain_with_attributes(3, bold.clone())
+        .build();
+    let delta = a.concat(&b);
+    assert_eq!(
+        delta.ops,
+        vec![
+            OpBuilder::retain(2).build(),
+            OpBuilder::delete(3).build(),
+            OpBuilder::retain_with_attributes(3, bold).build(),
+        ]
+    );
+    assert_eq!(delta.base_len(), a.base_len() + b.base_len());
+    assert_eq!(delta.target_len(), a.target_len() + b.target_len());
+}
+
+#[test]
+fn delta_compose_associativity_random_delta() {
+    for _ in 0..1000 {
+        let seed = thread_rng().gen();
+        let mut rng = Rng::from_seed(seed);
+        let s = rng.gen_string(20);
+        let a = rng.gen_formatted_delta(&s);
+        let after_a = a.apply(&s).unwrap();
+        let b = rng.gen_formatted_delta(&after_a);
+        let after_b = b.apply(&after_a).unwrap();
+        let c = rng.gen_formatted_delta(&after_b);
+
+        let document: RichTextDelta = DeltaBuilder::new().insert(&s).build();
+        let ab_c = a.compose(&b).unwrap().compose(&c).unwrap();
+        let a_bc = a.compose(&b.compose(&c).unwrap()).unwrap();
+        assert_eq!(
+            document.compose(&ab_c).unwrap(),
+            document.compose(&a_bc).unwrap(),
+            "seed: {:?}, s: {}, a: {}, b: {}, c: {}",
+            seed,
+            s,
+            a,
+            b,
+            c
+        );
+        assert_eq!(ab_c.apply(&s).unwrap(), c.apply(&after_b).unwrap(), "seed: {:?}", seed);
+    }
+}
+
+#[test]
+fn delta_invert_random_formatted_delta() {
+    for _ in 0..1000 {
+        let seed = thread_rng().gen();
+        let mut rng = Rng::from_seed(seed);
+        let s = rng.gen_string(20);
+        let document: RichTextDelta = DeltaBuilder::new().insert(&s).build();
+        let delta = rng.gen_formatted_delta(&s);
+        let undo = delta.invert(&document);
+        let restored = document.compose(&delta).unwrap().compose(&undo).unwrap();
+        assert_eq!(restored, document, "seed: {:?}, s: {}, delta: {}", seed, s, delta);
+    }
+}
+
+#[test]
+fn transform_random_formatted_delta() {
+    for _ in 0..1000 {
+        let seed = thread_rng().gen();
+        let mut rng = Rng::from_seed(seed);
+        let s = rng.gen_string(20);
+        let document: RichTextDelta = DeltaBuilder::new().insert(&s).build();
+        let a = rng.gen_formatted_delta(&s);
+        let b = rng.gen_formatted_delta(&s);
+        let (a_prime, b_prime) = a.transform(&b).unwrap();
+        assert_eq!(
+            document.compose(&a).unwrap().compose(&b_prime).unwrap(),
+            document.compose(&b).unwrap().compose(&a_prime).unwrap(),
+            "seed: {:?}, s: {}, a: {}, b: {}",
+            seed,
+            s,
+            a,
+            b
+        );
+    }
+}
+
+#[test]
+fn delta_insert_at_and_delete_range() {
+    let delta = RichTextDelta::insert_at(3, "X", None, 6).unwrap();
+    assert_eq!(delta.apply("abcdef").unwrap(), "abcXdef");
+
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let delta = RichTextDelta::insert_at(6, "!", Some(bold.clone()), 6).unwrap();
+    assert_eq!(
+        delta,
+        DeltaBuilder::new().retain(6).insert_with_attributes("!", bold).build()
+    );
+    assert!(RichTextDelta::insert_at(7, "X", None, 6).is_err());
+
+    let delta = RichTextDelta::delete_range(1, 4, 6).unwrap();
+    assert_eq!(delta.apply("abcdef").unwrap(), "aef");
+    assert_eq!(
+        RichTextDelta::delete_range(2, 2, 6).unwrap().apply("abcdef").unwrap(),
+        "abcdef"
+    );
+    assert!(RichTextDelta::delete_range(4, 1, 6).is_err());
+    assert!(RichTextDelta::delete_range(4, 7, 6).is_err());
+}
+
+#[test]
+fn delta_try_compose() {
+    let a: RichTextDelta = DeltaBuilder::new().insert("123").build();
+    let b: RichTextDelta = DeltaBuilder::new().retain(3).insert("4").build();
+    assert_eq!(a.try_compose(&b).unwrap(), a.compose(&b).unwrap());
+
+    // The chopped delta is still composed by compose, but rejected by try_compose.
+    let c: RichTextDelta = DeltaBuilder::new().insert("0").build();
+    assert_eq!(a.compose(&c).unwrap().to_delta_str(), r#"[{"insert":"0123"}]"#);
+    let err = a.try_compose(&c).unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::IncompatibleLength));
+    let err = a.try_compose(&DeltaBuilder::new().retain(5).build()).unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::IncompatibleLength));
+}
+
+#[test]
+fn delta_apply_to_delta() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let document: RichTextDelta = DeltaBuilder::new().insert("123456").build();
+    let format: RichTextDelta = DeltaBuilder::new()
+        .retain(1)
+        .retain_with_attributes(2, bold.clone())
+        .retain(3)
+        .build();
+    assert_eq!(
+        format.apply_to_delta(&document).unwrap(),
+        DeltaBuilder::new()
+            .insert("1")
+            .insert_with_attributes("23", bold)
+            .insert("456")
+            .build()
+    );
+
+    let change: RichTextDelta = DeltaBuilder::new().retain(6).insert("7").build();
+    let err = change.apply_to_delta(&format).unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::InvalidOperation));
+    let err = change
+        .apply_to_delta(&DeltaBuilder::new().insert("123").build())
+        .unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::IncompatibleLength));
+}
+
+#[test]
+fn delta_replace() {
+    let delta = RichTextDelta::replace(2, 5, "XY", None, 7).unwrap();
+    assert_eq!(delta.apply("abcdefg").unwrap(), "abXYfg");
+
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let delta = RichTextDelta::replace(2, 5, "XY", Some(bold.clone()), 7).unwrap();
+    assert_eq!(
+        delta,
+        DeltaBuilder::new()
+            .retain(2)
+            .insert_with_attributes("XY", bold)
+            .delete(3)
+            .retain(2)
+            .build()
+    );
+    assert!(RichTextDelta::replace(5, 2, "XY", None, 7).is_err());
+    assert!(RichTextDelta::replace(2, 8, "XY", None, 7).is_err());
+}
+
+#[test]
+fn delta_from_plain_text() {
+    let delta = RichTextDelta::from_plain_text("a\r\nb");
+    assert_eq!(delta.ops, vec![OpBuilder::insert("a\nb").build()]);
+    let mut lines = vec![];
+    delta.each_line(|line, _| {
+        lines.push(line);
+        true
+    });
+    assert_eq!(lines.len(), 2);
+
+    let delta = RichTextDelta::from_plain_text("a\rb\r\n\r\nc\n");
+    assert_eq!(delta.ops, vec![OpBuilder::insert("a\nb\n\nc\n").build()]);
+    assert!(RichTextDelta::from_plain_text("").is_empty());
+}
+
+#[test]
+fn delta_lines() {
+    assert_eq!(RichTextDelta::default().lines(), 1);
+    let header = AttributeBuilder::new().add_attr(RichTextAttribute::Header(1)).build();
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .insert("a")
+        .insert_with_attributes("\n", header)
+        .insert("b")
+        .build();
+    assert_eq!(delta.lines(), 2);
+    let delta: RichTextDelta = DeltaBuilder::new().insert("a\nb\n").build();
+    assert_eq!(delta.lines(), 3);
+
+    let mut count = 0;
+    delta.each_line(|_, _| {
+        count += 1;
+        true
+    });
+    assert_eq!(count, delta.lines());
+}
+
+#[test]
+fn delta_from_str() {
+    let delta = RichTextDelta::from("hi");
+    assert_eq!(delta.apply("").unwrap(), "hi");
+    assert_eq!(delta, DeltaBuilder::new().insert("hi").build());
+    assert_eq!(RichTextDelta::from("hi".to_owned()), delta);
+    assert!(RichTextDelta::from("").is_empty());
+
+    let ops = vec![
+        OpBuilder::insert("h").build(),
+        OpBuilder::insert("").build(),
+        OpBuilder::insert("i").build(),
+    ];
+    let collected = ops.into_iter().collect::<RichTextDelta>();
+    assert_eq!(collected, delta);
+}
+
+#[test]
+fn delta_attributes_at() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let italic = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Italic(true))
+        .build();
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .insert_with_attributes("123", bold.clone())
+        .insert_with_attributes("45", italic.clone())
+        .insert("\n")
+        .build();
+
+    assert_eq!(delta.attributes_at(0), bold);
+    assert_eq!(delta.attributes_at(1), bold);
+    // The boundary takes the attributes of the preceding insert.
+    assert_eq!(delta.attributes_at(3), bold);
+    assert_eq!(delta.attributes_at(4), italic);
+    assert_eq!(delta.attributes_at(5), italic);
+    assert_eq!(delta.attributes_at(6), RichTextAttributes::default());
+    assert_eq!(delta.attributes_at(7), RichTextAttributes::default());
+    assert_eq!(RichTextDelta::default().attributes_at(0), RichTextAttributes::default());
+}
+
+#[test]
+fn delta_hash_independent_of_attribute_order() {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+    fn hash_of(delta: &RichTextDelta) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        delta.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let attributes = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Bold(true))
+        .add_attr(RichTextAttribute::Italic(true))
+        .add_attr(RichTextAttribute::Underline(true))
+        .add_attr(RichTextAttribute::Color("red".to_owned()))
+        .add_attr(RichTextAttribute::Header(1))
+        .build();
+    let reversed_attributes = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Header(1))
+        .add_attr(RichTextAttribute::Color("red".to_owned()))
+        .add_attr(RichTextAttribute::Underline(true))
+        .add_attr(RichTextAttribute::Italic(true))
+        .add_attr(RichTextAttribute::Bold(true))
+        .build();
+
+    let a: RichTextDelta = DeltaBuilder::new().insert_with_attributes("123", attributes).build();
+    let b: RichTextDelta = DeltaBuilder::new()
+        .insert_with_attributes("123", reversed_attributes)
+        .build();
+    assert_eq!(a, b);
+    assert_eq!(hash_of(&a), hash_of(&b));
+
+    let mut deltas = std::collections::HashSet::new();
+    deltas.insert(a);
+    deltas.insert(b);
+    deltas.insert(DeltaBuilder::new().insert("123").build());
+    assert_eq!(deltas.len(), 2);
+}
+
+#[test]
+fn delta_retain_to_end() {
+    let s = "hello world";
+    let mut delta: RichTextDelta = DeltaBuilder::new().retain(5).insert(",").build();
+    delta.retain_to_end(s.len());
+    assert_eq!(delta, DeltaBuilder::new().retain(5).insert(",").retain(6).build());
+    assert_eq!(delta.utf16_base_len, s.len());
+    assert_eq!(delta.apply(s).unwrap(), "hello, world");
+
+    // The delta that covers the base length is left as it is.
+    let expected = delta.clone();
+    delta.retain_to_end(s.len());
+    assert_eq!(delta, expected);
+
+    let mut delta: RichTextDelta = DeltaBuilder::new().delete(11).build();
+    delta.retain_to_end(s.len());
+    assert_eq!(delta, DeltaBuilder::new().delete(11).build());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn delta_retain_to_end_overrun() {
+    let mut delta: RichTextDelta = DeltaBuilder::new().retain(5).build();
+    delta.retain_to_end(3);
+}
+
+#[test]
+fn operation_split_at_grapheme() {
+    let family = "👨\u{200d}👩\u{200d}👧";
+    let s = format!("{}e\u{301}", family);
+    let op: RichTextOperation = OpBuilder::insert(&s).build();
+    match &op {
+        Operation::Insert(insert) => {
+            assert_eq!(insert.num_graphemes(), 2);
+            assert_eq!(insert.s.chars().count(), 7);
+        }
+        _ => panic!(),
+    }
+
+    let (left, right) = op.split_at_grapheme(1);
+    assert_eq!(left.unwrap().get_data(), family);
+    assert_eq!(right.unwrap().get_data(), "e\u{301}");
+
+    // Splitting inside the cluster by the code unit cuts the emoji, the grapheme split never does.
+    let (left, _) = op.split(2);
+    assert_eq!(left.unwrap().get_data(), "👨");
+    let (left, right) = op.split_at_grapheme(2);
+    assert_eq!(left.unwrap(), op);
+    assert!(right.is_none());
+
+    let op: RichTextOperation = OpBuilder::retain(5).build();
+    let (left, right) = op.split_at_grapheme(2);
+    assert_eq!(left.unwrap().len(), 2);
+    assert_eq!(right.unwrap().len(), 3);
+}
+
+#[test]
+fn delta_plain_text() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let image = Embed::new("image", "https://appflowy.io/logo.png");
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .insert("Hello ")
+        .insert_with_attributes("world", bold)
+        .insert_embed(image, RichTextAttributes::default())
+        .insert("\n")
+        .build();
+    assert_eq!(delta.plain_text(), format!("Hello world{}\n", EMBED_PLACEHOLDER));
+
+    let delta: RichTextDelta = DeltaBuilder::new().retain(2).insert("abc").delete(1).build();
+    assert_eq!(delta.plain_text(), "abc");
+    assert_eq!(RichTextDelta::default().plain_text(), "");
+}
+
+#[test]
+fn operation_canonical_order() {
+    let insert_a: RichTextOperation = OpBuilder::insert("a").build();
+    let insert_b: RichTextOperation = OpBuilder::insert("b").build();
+    let retain: RichTextOperation = OpBuilder::retain(1).build();
+    let delete: RichTextOperation = OpBuilder::delete(1).build();
+    assert_eq!(insert_a.canonical_cmp(&retain), std::cmp::Ordering::Less);
+    assert_eq!(retain.canonical_cmp(&delete), std::cmp::Ordering::Less);
+    assert_eq!(insert_a.canonical_cmp(&insert_b), std::cmp::Ordering::Equal);
+
+    let mut ops = vec![delete.clone(), insert_a.clone(), retain.clone(), insert_b.clone()];
+    ops.sort_by(|a, b| a.canonical_cmp(b));
+    assert_eq!(
+        ops,
+        vec![insert_a.clone(), insert_b.clone(), retain.clone(), delete.clone()]
+    );
+
+    // The normalized delta sorts the operations between the retains only.
+    let mut delta = RichTextDelta::default();
+    delta.ops = vec![
+        delete.clone(),
+        insert_a,
+        retain,
+        OpBuilder::delete(2).build(),
+        insert_b,
+        delete,
+    ];
+    delta.normalize();
+    let expected: RichTextDelta = DeltaBuilder::new()
+        .insert("a")
+        .delete(1)
+        .retain(1)
+        .insert("b")
+        .delete(3)
+        .build();
+    assert_eq!(delta, expected);
+    assert_eq!(delta.apply("12345").unwrap(), "a2b");
+}
+
+#[test]
+fn delta_compose_attributes_only() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let italic = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Italic(true))
+        .build();
+    let doc: RichTextDelta = DeltaBuilder::new()
+        .insert("123")
+        .insert_with_attributes("456", italic.clone())
+        .build();
+    let format: RichTextDelta = DeltaBuilder::new()
+        .retain(2)
+        .retain_with_attributes(2, bold.clone())
+        .build();
+    let mut bold_italic = bold.clone();
+    bold_italic.extend_other(italic.clone());
+    let expected: RichTextDelta = DeltaBuilder::new()
+        .insert("12")
+        .insert_with_attributes("3", bold)
+        .insert_with_attributes("4", bold_italic)
+        .insert_with_attributes("56", italic)
+        .build();
+    assert_eq!(doc.compose_attributes_only(&format).unwrap(), expected);
+    assert_eq!(doc.compose(&format).unwrap(), expected);
+
+    let edit: RichTextDelta = DeltaBuilder::new().retain(2).insert("a").build();
+    assert!(matches!(
+        doc.compose_attributes_only(&edit).unwrap_err().code,
+        OTErrorCode::ComposeOperationFail
+    ));
+}
+
+#[test]
+fn delta_compose_attributes_only_random_delta() {
+    let mut rng = Rng::default();
+    for _ in 0..1000 {
+        let s = rng.gen_string(20);
+        let delta = rng.gen_formatted_delta(&s);
+        let mut format = rng.gen_format_delta(delta.utf16_target_len);
+        let composed = delta.compose_attributes_only(&format).unwrap();
+
+        // The trailing insert makes the compose take the general path.
+        format.insert("1", RichTextAttributes::default());
+        let insert: RichTextDelta = DeltaBuilder::new()
+            .retain(composed.utf16_target_len)
+            .insert("1")
+            .build();
+        assert_eq!(delta.compose(&format).unwrap(), composed.compose(&insert).unwrap());
+    }
+}
+
+#[test]
+fn insert_split_at_char() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let insert: lib_ot::core::Insert<RichTextAttributes> = lib_ot::core::Insert {
+        s: "日本語".into(),
+        attributes: bold.clone(),
+        embed: None,
+    };
+
+    let (left, right) = insert.split_at_char(1);
+    assert_eq!(left.s.as_str(), "日");
+    assert_eq!(right.s.as_str(), "本語");
+    assert_eq!(left.attributes, bold);
+    assert_eq!(right.attributes, bold);
+
+    let (left, right) = insert.split_at_char(2);
+    assert_eq!(left.s.as_str(), "日本");
+    assert_eq!(right.s.as_str(), "語");
+
+    let (left, right) = insert.split_at_char(5);
+    assert_eq!(left, insert);
+    assert!(right.s.is_empty());
+}
+
+#[test]
+fn delta_removed_text() {
+    let s = "Hello, world😁!";
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .retain(5)
+        .delete(2)
+        .insert(" ")
+        .retain(5)
+        .delete(2)
+        .retain(1)
+        .build();
+    assert_eq!(delta.removed_text(s).unwrap(), ", 😁");
+    assert_eq!(delta.apply(s).unwrap(), "Hello world!");
+
+    let delta: RichTextDelta = DeltaBuilder::new().retain(3).build();
+    assert!(delta.removed_text(s).is_err());
+}
+
+#[test]
+fn delta_transform_insert_with_gravity() {
+    let transformed_text = |a_gravity: Option<Gravity>, b_gravity: Option<Gravity>| {
+        let attributes = |gravity: Option<Gravity>| match gravity {
+            Some(gravity) => AttributeBuilder::new()
+                .add_attr(RichTextAttribute::Gravity(gravity))
+                .build(),
+            None => RichTextAttributes::default(),
+        };
+        let document: RichTextDelta = DeltaBuilder::new().insert("12").build();
+        let a: RichTextDelta = DeltaBuilder::new()
+            .retain(1)
+            .insert_with_attributes("a", attributes(a_gravity))
+            .retain(1)
+            .build();
+        let b: RichTextDelta = DeltaBuilder::new()
+            .retain(1)
+            .insert_with_attributes("b", attributes(b_gravity))
+            .retain(1)
+            .build();
+
+        let (a_prime, b_prime) = a.transform(&b).unwrap();
+        let ab_prime = document.compose(&a).unwrap().compose(&b_prime).unwrap();
+        let ba_prime = document.compose(&b).unwrap().compose(&a_prime).unwrap();
+        assert_eq!(ab_prime, ba_prime);
+
+        // The gravity decides the order no matter which side has the priority.
+        let b_prime_without_priority = a.transform_with_priority(&b, false).unwrap();
+        let ab_prime_without_priority = document
+            .compose(&a)
+            .unwrap()
+            .compose(&b_prime_without_priority)
+            .unwrap();
+        let a_prime_without_priority = b.transform_with_priority(&a, true).unwrap();
+        let ba_prime_without_priority = document
+            .compose(&b)
+            .unwrap()
+            .compose(&a_prime_without_priority)
+            .unwrap();
+        assert_eq!(ab_prime_without_priority, ba_prime_without_priority);
+        if a_gravity != b_gravity {
+            assert_eq!(ab_prime, ab_prime_without_priority);
+        }
+        ab_prime.plain_text()
+    };
+
+    // Without the gravity, the insert of a goes first because it has the priority.
+    assert_eq!(transformed_text(None, None), "1ab2");
+    assert_eq!(transformed_text(Some(Gravity::Right), None), "1ba2");
+    assert_eq!(transformed_text(None, Some(Gravity::Left)), "1ba2");
+    assert_eq!(transformed_text(Some(Gravity::Left), Some(Gravity::Right)), "1ab2");
+    assert_eq!(transformed_text(Some(Gravity::Right), Some(Gravity::Right)), "1ab2");
+}
+
+#[test]
+fn delta_map_attributes() {
+    let red = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Color("red".to_owned()))
+        .build();
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut delta: RichTextDelta = DeltaBuilder::new()
+        .insert_with_attributes("123", red.clone())
+        .insert("456")
+        .insert_with_attributes("789", bold.clone())
+        .retain_with_attributes(2, red)
+        .delete(1)
+        .build();
+
+    let mut visited = 0;
+    delta.map_attributes(|attributes| {
+        visited += 1;
+        if let Some(value) = attributes.get(&RichTextAttributeKey::Color).cloned() {
+            attributes.remove(RichTextAttributeKey::Color);
+            attributes.insert(RichTextAttributeKey::Background, value);
+        }
+    });
+    assert_eq!(visited, 3);
+
+    let background = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Background("red".to_owned()))
+        .build();
+    let expected: RichTextDelta = DeltaBuilder::new()
+        .insert_with_attributes("123", background.clone())
+        .insert("456")
+        .insert_with_attributes("789", bold)
+        .retain_with_attributes(2, background)
+        .delete(1)
+        .build();
+    assert_eq!(delta, expected);
+
+    // The inserts merge once their attributes are cleared.
+    delta.map_attributes(|attributes| attributes.clear());
+    let expected: RichTextDelta = DeltaBuilder::new().insert("123456789").retain(2).delete(1).build();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn delta_trim_start_and_end() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut delta: RichTextDelta = DeltaBuilder::new()
+        .retain(3)
+        .retain_with_attributes(2, bold.clone())
+        .insert("123")
+        .retain(4)
+        .build();
+    assert_eq!(delta.trim_start(), 3);
+    delta.trim_end();
+    let expected: RichTextDelta = DeltaBuilder::new()
+        .retain_with_attributes(2, bold.clone())
+        .insert("123")
+        .build();
+    assert_eq!(delta, expected);
+
+    // The retains with attributes are kept.
+    let mut delta: RichTextDelta = DeltaBuilder::new()
+        .retain_with_attributes(2, bold.clone())
+        .insert("123")
+        .retain_with_attributes(2, bold)
+        .build();
+    let expected = delta.clone();
+    assert_eq!(delta.trim_start(), 0);
+    delta.trim_end();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn delta_apply_with_events() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let plain = RichTextAttributes::default();
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .retain(2)
+        .insert_with_attributes("abc", bold.clone())
+        .delete(3)
+        .retain_with_attributes(1, bold.clone())
+        .build();
+
+    let mut events = vec![];
+    let s = delta.apply_with("123456", |event| events.push(event)).unwrap();
+    assert_eq!(s, "12abc6");
+    assert_eq!(s, delta.apply("123456").unwrap());
+
+    let expected = vec![
+        ApplyEvent::Retain {
+            interval: Interval::new(0, 2),
+            attributes: &plain,
+        },
+        ApplyEvent::Insert {
+            interval: Interval::new(2, 5),
+            s: "abc",
+            attributes: &bold,
+        },
+        ApplyEvent::Delete { index: 5, n: 3 },
+        ApplyEvent::Retain {
+            interval: Interval::new(5, 6),
+            attributes: &bold,
+        },
+    ];
+    assert_eq!(events, expected);
+}
+
+#[test]
+fn delta_apply_overrun() {
+    let delta: RichTextDelta = DeltaBuilder::new().retain(10).build();
+    let err = delta.apply("abc").unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::IncompatibleLength));
+    assert!(err.msg.contains("at 0"), "{}", err.msg);
+    assert!(delta.apply_rope(&ropey::Rope::from_str("abc")).is_err());
+
+    // The operations pushed directly don't update the lengths, the overrun is still caught.
+    let mut delta: RichTextDelta = DeltaBuilder::new().retain(1).delete(1).build();
+    delta.ops.push(OpBuilder::insert("x").build());
+    delta.ops.push(OpBuilder::retain(5).build());
+    let err = delta.apply("abc").unwrap_err();
+    assert!(err.msg.contains("at 3"), "{}", err.msg);
+    assert!(delta.removed_text("abc").is_err());
+}
+
+#[test]
+fn delta_compose_in_place() {
+    let mut delta: RichTextDelta = DeltaBuilder::new().insert("123").build();
+    let typing: RichTextDelta = DeltaBuilder::new().retain(3).insert("4").build();
+    let expected = delta.compose(&typing).unwrap();
+    delta.compose_in_place(&typing).unwrap();
+    assert_eq!(delta, expected);
+    assert_eq!(delta.apply("").unwrap(), "1234");
+
+    // The leading retain goes beyond the end of the delta.
+    let mut delta: RichTextDelta = DeltaBuilder::new().insert("1").retain(2).build();
+    let other: RichTextDelta = DeltaBuilder::new().retain(5).insert("x").build();
+    let expected = delta.compose(&other).unwrap();
+    delta.compose_in_place(&other).unwrap();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn delta_compose_in_place_random_delta() {
+    let mut rng = Rng::default();
+    for _ in 0..1000 {
+        let s = rng.gen_string(20);
+        let a = rng.gen_formatted_delta(&s);
+        let after_a = a.apply(&s).unwrap();
+        let b = rng.gen_formatted_delta(&after_a);
+
+        let mut composed = a.clone();
+        composed.compose_in_place(&b).unwrap();
+        assert_eq!(composed, a.compose(&b).unwrap());
+        assert_eq!(composed.apply(&s).unwrap(), b.apply(&after_a).unwrap());
+    }
+}
+
+#[test]
+fn delta_apply_checked() {
+    let base = "123";
+    let checksum = BaseChecksum::new(base);
+    let mut delta = RichTextDelta::default();
+    delta.delete(3);
+    delta.insert("456789", RichTextAttributes::default());
+
+    let s = delta.apply_checked(base, &checksum).unwrap();
+    assert_eq!(s, "456789");
+
+    // The length check alone accepts the same delta applied twice.
+    let mut insert = RichTextDelta::default();
+    insert.retain(3, RichTextAttributes::default());
+    insert.insert("!", RichTextAttributes::default());
+    let s = insert.apply_checked(base, &checksum).unwrap();
+    assert_eq!(s, "123!");
+    let err = insert.apply_checked("12!", &checksum).unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::ChecksumMismatch));
+    let err = delta.apply_checked(&s, &checksum).unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::ChecksumMismatch));
+}
+
+#[test]
+fn delta_char_len_of_inserts_and_deletes() {
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .retain(2)
+        .insert("12")
+        .delete(3)
+        .retain(1)
+        .insert("345")
+        .build();
+    assert_eq!(delta.char_len_of_inserts(), 5);
+    assert_eq!(delta.char_len_of_deletes(), 3);
+    assert_eq!(
+        delta.base_len() + delta.char_len_of_inserts() - delta.char_len_of_deletes(),
+        delta.target_len()
+    );
+
+    let mut rng = Rng::default();
+    for _ in 0..1000 {
+        let s = rng.gen_string(20);
+        let delta = rng.gen_delta(&s);
+        assert_eq!(
+            delta.base_len() + delta.char_len_of_inserts() - delta.char_len_of_deletes(),
+            delta.target_len()
+        );
+    }
+}
+
+#[test]
+fn op_builder_build_checked() {
+    let err = RichTextOpBuilder::insert("").build_checked().unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::InvalidOperation));
+    let err = RichTextOpBuilder::retain(0).build_checked().unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::InvalidOperation));
+    let err = RichTextOpBuilder::delete(1)
+        .attributes(RichTextAttribute::Bold(true).into())
+        .build_checked()
+        .unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::InvalidOperation));
+
+    let op = RichTextOpBuilder::insert("x").build_checked().unwrap();
+    assert_eq!(op, OpBuilder::insert("x").build());
+}
+
+#[test]
+fn delta_rebase() {
+    let doc: RichTextDelta = DeltaBuilder::new().insert("hello\n").build();
+    // The remote user appends " world" while the local user types "!" at the same position and
+    // bolds the "hello".
+    let remote: RichTextDelta = DeltaBuilder::new().retain(5).insert(" world").build();
+    let local: RichTextDelta = DeltaBuilder::new()
+        .retain_with_attributes(5, RichTextAttribute::Bold(true).into())
+        .insert("!")
+        .build();
+
+    let local_prime = local.rebase(&remote).unwrap();
+    assert_eq!(local_prime.base_len(), remote.target_len());
+    let document = doc.compose(&remote).unwrap().compose(&local_prime).unwrap();
+    assert_eq!(document.apply("").unwrap(), "hello world!\n");
+    assert_eq!(
+        document.ops[0],
+        OpBuilder::insert("hello")
+            .attributes(RichTextAttribute::Bold(true).into())
+            .build()
+    );
+
+    // It converges with the remote delta rebased onto the local delta.
+    let remote_prime = local.transform_with_priority(&remote, false).unwrap();
+    let other = doc.compose(&local).unwrap().compose(&remote_prime).unwrap();
+    assert_eq!(document, other);
+}
+
+#[test]
+fn delta_from_lines() {
+    let bullet: RichTextAttributes = RichTextAttribute::Bullet(true).into();
+    let header: RichTextAttributes = RichTextAttribute::Header(1).into();
+    let delta = RichTextDelta::from_lines(&[
+        ("Title".to_owned(), header.clone()),
+        ("a".to_owned(), bullet.clone()),
+        ("b\nc".to_owned(), bullet.clone()),
+        ("end".to_owned(), RichTextAttributes::default()),
+    ]);
+    assert_eq!(delta.apply("").unwrap(), "Title\na\nb\nc\nend\n");
+
+    let mut lines = vec![];
+    delta.each_line(|line, attributes| {
+        lines.push((line.apply("").unwrap(), attributes.clone()));
+        true
+    });
+    assert_eq!(
+        lines,
+        vec![
+            ("Title".to_owned(), header),
+            ("a".to_owned(), bullet.clone()),
+            ("b".to_owned(), bullet.clone()),
+            ("c".to_owned(), bullet),
+            ("end".to_owned(), RichTextAttributes::default()),
+            ("".to_owned(), RichTextAttributes::default()),
+        ]
+    );
+}
+
+#[test]
+fn delta_transform_selection() {
+    // The document is "0123456789" and "2345" is selected.
+    let selection = Interval::new(2, 6);
+
+    // Deletes "4567", which straddles the end of the selection.
+    let delta: RichTextDelta = DeltaBuilder::new().retain(4).delete(4).retain(2).build();
+    assert_eq!(delta.transform_selection(selection, false), Interval::new(2, 4));
+
+    // Deletes "123456", the selection collapses to a caret.
+    let delta: RichTextDelta = DeltaBuilder::new().retain(1).delete(6).retain(3).build();
+    assert_eq!(delta.transform_selection(selection, false), Interval::new(1, 1));
+
+    // Inserts within the selection.
+    let delta: RichTextDelta = DeltaBuilder::new().retain(3).insert("ab").retain(7).build();
+    assert_eq!(delta.transform_selection(selection, false), Interval::new(2, 8));
+
+    // Inserts at both ends of the selection.
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .retain(2)
+        .insert("ab")
+        .retain(4)
+        .insert("cd")
+        .retain(4)
+        .build();
+    assert_eq!(delta.transform_selection(selection, true), Interval::new(2, 10));
+    assert_eq!(delta.transform_selection(selection, false), Interval::new(4, 8));
+}
+
+#[test]
+fn operation_append_text() {
+    let bold: RichTextAttributes = RichTextAttribute::Bold(true).into();
+    let mut op = OpBuilder::insert("1").attributes(bold.clone()).build();
+    for s in ["2", "3", "😁"] {
+        assert!(op.append_text(s));
+    }
+    assert_eq!(op, OpBuilder::insert("123😁").attributes(bold).build());
+    assert_eq!(op.len(), 5);
+
+    let mut op: RichTextOperation = OpBuilder::retain(1).build();
+    assert!(!op.append_text("2"));
+    assert_eq!(op, OpBuilder::retain(1).build());
+
+    let mut op: RichTextOperation = OpBuilder::embed(Embed::new("image", "a.png")).build();
+    assert!(!op.append_text("2"));
+    assert_eq!(op.len(), 1);
+}
+
+#[test]
+fn delta_is_prefix_of() {
+    let bold: RichTextAttributes = RichTextAttribute::Bold(true).into();
+    let old: RichTextDelta = DeltaBuilder::new()
+        .insert_with_attributes("123", bold.clone())
+        .insert("45")
+        .build();
+    let new: RichTextDelta = DeltaBuilder::new()
+        .insert_with_attributes("123", bold.clone())
+        .insert("456")
+        .insert_with_attributes("789", bold.clone())
+        .build();
+    assert!(old.is_prefix_of(&new));
+    assert!(!new.is_prefix_of(&old));
+    assert!(RichTextDelta::default().is_prefix_of(&new));
+    assert!(new.is_prefix_of(&new));
+
+    // The last insert "45" is the beginning of the insert "456".
+    let suffix = old.suffix_after(&new).unwrap();
+    assert_eq!(
+        suffix,
+        DeltaBuilder::new()
+            .insert("6")
+            .insert_with_attributes("789", bold.clone())
+            .build()
+    );
+    assert_eq!(old.concat(&suffix), new);
+    assert_eq!(new.suffix_after(&new), Some(RichTextDelta::default()));
+
+    // Only the last operation can be shared partially.
+    let old: RichTextDelta = DeltaBuilder::new()
+        .insert_with_attributes("12", bold.clone())
+        .insert("45")
+        .build();
+    assert_eq!(old.suffix_after(&new), None);
+
+    // The attributes must be the same.
+    let old: RichTextDelta = DeltaBuilder::new().insert("123").build();
+    assert!(!old.is_prefix_of(&new));
+
+    let old: RichTextDelta = DeltaBuilder::new().retain(3).build();
+    let new: RichTextDelta = DeltaBuilder::new().retain(5).insert("1").build();
+    assert_eq!(
+        old.suffix_after(&new).unwrap(),
+        DeltaBuilder::new().retain(2).insert("1").build()
+    );
+}
+
+#[test]
+fn delta_transform_with_tie_break() {
+    let a: RichTextDelta = DeltaBuilder::new().retain(1).insert("a").retain(1).build();
+    let b: RichTextDelta = DeltaBuilder::new().retain(1).insert("b").retain(1).build();
+    assert_eq!(
+        a.transform_with_tie_break(&b, TieBreak::Left).unwrap(),
+        a.transform_with_priority(&b, true).unwrap()
+    );
+    assert_eq!(
+        a.transform_with_tie_break(&b, TieBreak::Right).unwrap(),
+        a.transform_with_priority(&b, false).unwrap()
+    );
+
+    // Three editors of "xy" insert at the same position concurrently. Every editor applies its own
+    // delta first and then the other two in either order, each of them is transformed against the
+    // deltas applied before it.
+    let doc: RichTextDelta = DeltaBuilder::new().insert("xy").build();
+    let deltas = vec![
+        (2, a),
+        (3, b),
+        (1, DeltaBuilder::new().retain(1).insert("c").retain(1).build()),
+    ];
+    let orders = vec![[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+    for order in orders {
+        let mut applied: Vec<(u64, RichTextDelta)> = vec![];
+        for i in order {
+            let (site_id, mut delta) = deltas[i].clone();
+            for (applied_site_id, applied_delta) in &applied {
+                let tie_break = TieBreak::ById {
+                    site_id: *applied_site_id,
+                    other_site_id: site_id,
+                };
+                delta = applied_delta.transform_with_tie_break(&delta, tie_break).unwrap();
+            }
+            applied.push((site_id, delta));
+        }
+
+        let document = applied
+            .iter()
+            .try_fold(doc.clone(), |document, (_, delta)| document.compose(delta))
+            .unwrap();
+        assert_eq!(document.apply("").unwrap(), "xcaby");
+    }
+}
+
+#[test]
+fn operation_as_variant() {
+    let mut insert: RichTextOperation = OpBuilder::insert("123").build();
+    let mut retain: RichTextOperation = OpBuilder::retain(2).build();
+    let mut delete: RichTextOperation = OpBuilder::delete(3).build();
+
+    assert_eq!(insert.as_insert().unwrap().s.as_str(), "123");
+    assert!(insert.as_retain().is_none());
+    assert!(insert.as_delete().is_none());
+    assert_eq!(retain.as_retain().unwrap().n, 2);
+    assert!(retain.as_insert().is_none());
+    assert!(retain.as_delete().is_none());
+    assert_eq!(delete.as_delete(), Some(3));
+    assert!(delete.as_insert().is_none());
+    assert!(delete.as_retain().is_none());
+
+    insert.as_insert_mut().unwrap().attributes = RichTextAttribute::Bold(true).into();
+    assert_eq!(
+        insert,
+        OpBuilder::insert("123")
+            .attributes(RichTextAttribute::Bold(true).into())
+            .build()
+    );
+    retain.as_retain_mut().unwrap().n = 5;
+    assert_eq!(retain.len(), 5);
+    *delete.as_delete_mut().unwrap() = 1;
+    assert_eq!(delete, OpBuilder::delete(1).build());
+    assert!(insert.as_retain_mut().is_none());
+    assert!(insert.as_delete_mut().is_none());
+    assert!(retain.as_insert_mut().is_none());
+}
+
+#[test]
+fn delta_direction_of_rtl_text() {
+    let rtl: RichTextAttributes = RichTextAttribute::Direction("rtl").into();
+    let document: RichTextDelta = DeltaBuilder::new()
+        .insert("abc\n")
+        .insert("مرحبا")
+        .insert_with_attributes("\n", rtl)
+        .build();
+    assert_eq!(document.direction_at(0), None);
+    assert_eq!(document.direction_at(3), None);
+    assert_eq!(document.direction_at(4), Some("rtl"));
+    assert_eq!(document.direction_at(9), Some("rtl"));
+    assert_eq!(document.direction_at(10), None);
+    assert!(document.is_direction_boundary(4));
+    assert!(!document.is_direction_boundary(3));
+    assert!(!document.is_direction_boundary(6));
+    assert!(!document.is_direction_boundary(0));
+    assert!(!document.is_direction_boundary(10));
+
+    // The offsets are logical, inserting the Arabic text moves the cursor by its length.
+    let delta: RichTextDelta = DeltaBuilder::new().retain(6).insert("سلام ").retain(4).build();
+    let cursor = 8;
+    assert_eq!(delta.transform_index(cursor, false), 13);
+    let s = delta.apply(&document.apply("").unwrap()).unwrap();
+    assert_eq!(s, "abc\nمرسلام حبا\n");
+    let document = document.compose(&delta).unwrap();
+    assert_eq!(document.direction_at(13), Some("rtl"));
+    assert!(document.is_direction_boundary(4));
+}
+
+#[test]
+fn document_apply_undo_redo() {
+    let mut document: Document<RichTextAttributes> = Document::default();
+    let insert: RichTextDelta = DeltaBuilder::new().insert("123").build();
+    let undo_insert = document.apply(&insert).unwrap();
+    assert_eq!(document.version(), 1);
+    assert_eq!(document.delta().to_str().unwrap(), "123");
+
+    let bold: RichTextDelta = DeltaBuilder::new()
+        .retain(1)
+        .retain_with_attributes(2, RichTextAttribute::Bold(true).into())
+        .build();
+    let undo_bold = document.apply(&bold).unwrap();
+    let bolded = document.snapshot();
+    assert_eq!(bolded.version, 2);
+
+    // Undo both of them, the version keeps increasing.
+    let redo_bold = document.apply(&undo_bold).unwrap();
+    assert_eq!(document.delta(), &insert);
+    let redo_insert = document.apply(&undo_insert).unwrap();
+    assert!(document.delta().is_empty());
+    assert_eq!(document.version(), 4);
+
+    // Redo them.
+    document.apply(&redo_insert).unwrap();
+    document.apply(&redo_bold).unwrap();
+    assert_eq!(document.delta(), &bolded.delta);
+    assert_eq!(document.version(), 6);
+
+    // The delta that isn't based on the document is rejected.
+    let err = document.apply(&insert).unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::IncompatibleLength));
+    assert_eq!(document.version(), 6);
+
+    document.restore(bolded.clone());
+    assert_eq!(document.snapshot(), bolded);
+}
+
+#[test]
+fn document_subscribe() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let notified = Rc::new(RefCell::new(vec![]));
+    let mut document: Document<RichTextAttributes> = Document::default();
+    for observer in ["first", "second"] {
+        let notified = notified.clone();
+        document.subscribe(move |delta, version| {
+            notified.borrow_mut().push((observer, delta.to_delta_str(), version));
+        });
+    }
+
+    let insert: RichTextDelta = DeltaBuilder::new().insert("123").build();
+    document.apply(&insert).unwrap();
+    let delete: RichTextDelta = DeltaBuilder::new().retain(1).delete(2).build();
+    document.apply(&delete).unwrap();
+
+    // The rejected delta isn't notified.
+    assert!(document.apply(&insert).is_err());
+    assert_eq!(
+        notified.borrow().as_slice(),
+        &[
+            ("first", insert.to_delta_str(), 1),
+            ("second", insert.to_delta_str(), 1),
+            ("first", delete.to_delta_str(), 2),
+            ("second", delete.to_delta_str(), 2),
+        ]
+    );
+    assert_eq!(document.delta().to_str().unwrap(), "1");
+}
+
+#[test]
+fn delta_format() {
+    let bold: RichTextAttributes = RichTextAttribute::Bold(true).into();
+    let document: RichTextDelta = DeltaBuilder::new().insert("0123456789").build();
+    let delta = document.format(3, 7, bold.clone()).unwrap();
+    assert_eq!(
+        delta,
+        DeltaBuilder::new()
+            .retain(3)
+            .retain_with_attributes(4, bold.clone())
+            .retain(3)
+            .build()
+    );
+    assert_eq!(
+        document.compose(&delta).unwrap(),
+        DeltaBuilder::new()
+            .insert("012")
+            .insert_with_attributes("3456", bold.clone())
+            .insert("789")
+            .build()
+    );
+
+    assert_eq!(
+        document.format(0, 10, bold.clone()).unwrap(),
+        DeltaBuilder::new().retain_with_attributes(10, bold.clone()).build()
+    );
+    let err = document.format(3, 11, bold).unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::IntervalOutOfBound));
+}
+
+#[test]
+fn operation_byte_length() {
+    let op: RichTextOperation = OpBuilder::insert("aé中😁").build();
+    assert_eq!(op.byte_length(), Some(10));
+    assert_eq!(op.len(), 5);
+    let op: RichTextOperation = OpBuilder::insert("abc").build();
+    assert_eq!(op.byte_length(), Some(3));
+
+    let op: RichTextOperation = OpBuilder::retain(3).build();
+    assert_eq!(op.byte_length(), None);
+    let op: RichTextOperation = OpBuilder::delete(3).build();
+    assert_eq!(op.byte_length(), None);
+}
+
+#[test]
+fn insert_embed_accessors() {
+    let text: lib_ot::core::Insert<RichTextAttributes> = "123".into();
+    assert!(!text.is_embed());
+    assert_eq!(text.embed_type(), None);
+    assert_eq!(text.embed_value(), None);
+
+    let image: lib_ot::core::Insert<RichTextAttributes> = Embed::new("image", "https://appflowy.io/logo.png").into();
+    assert!(image.is_embed());
+    assert_eq!(image.embed_type(), Some("image"));
+    assert_eq!(
+        image.embed_value().and_then(|value| value.as_str()),
+        Some("https://appflowy.io/logo.png")
+    );
+}
+
+#[test]
+fn delta_interned_apply_identically() {
+    let mut rng = Rng::default();
+    let mut interner = StrInterner::new();
+    for _ in 0..1000 {
+        let s = rng.gen_string(20);
+        let a = rng.gen_formatted_delta(&s);
+        let b = rng.gen_formatted_delta(&s);
+        let mut interned_a = a.clone();
+        interned_a.intern(&mut interner);
+        assert_eq!(interned_a, a);
+        assert_eq!(interned_a.apply(&s).unwrap(), a.apply(&s).unwrap());
+
+        let after_a = a.apply(&s).unwrap();
+        let c = rng.gen_formatted_delta(&after_a);
+        assert_eq!(interned_a.compose(&c).unwrap(), a.compose(&c).unwrap());
+        assert_eq!(interned_a.transform(&b).unwrap(), a.transform(&b).unwrap());
+    }
+}
+
+#[test]
+fn delta_interned_memory() {
+    let words = [
+        "the", " ", "quick", "brown", "fox", "jumps", "over", "lazy", "dog", "\n",
+    ];
+    let bold: RichTextAttributes = RichTextAttribute::Bold(true).into();
+    let mut delta = RichTextDelta::new();
+    for i in 0..10_000 {
+        // The alternating format keeps the adjacent inserts from merging.
+        let attributes = if i % 2 == 0 {
+            bold.clone()
+        } else {
+            RichTextAttributes::default()
+        };
+        delta.insert(words[i % words.len()], attributes);
+    }
+    // Counts the bytes of the distinct strings the inserts point to.
+    let heap_bytes = |delta: &RichTextDelta| {
+        let mut seen = std::collections::HashSet::new();
+        delta
+            .ops
+            .iter()
+            .filter_map(|op| match op {
+                Operation::Insert(insert) if seen.insert(insert.s.as_ptr()) => Some(insert.s.len()),
+                _ => None,
+            })
+            .sum::<usize>()
+    };
+
+    let mut interner = StrInterner::new();
+    let mut interned = delta.clone();
+    interned.intern(&mut interner);
+    assert_eq!(interner.len(), words.len());
+    assert_eq!(heap_bytes(&delta), 34_000);
+    assert_eq!(heap_bytes(&interned), 34);
+    assert_eq!(interned.to_str().unwrap(), delta.to_str().unwrap());
+    assert_eq!(interned.to_delta_str(), delta.to_delta_str());
+}
+
+#[test]
+fn delta_compose_tiny_ops_over_long_op() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let italic = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Italic(true))
+        .build();
+    let bold_italic = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Bold(true))
+        .add_attr(RichTextAttribute::Italic(true))
+        .build();
+    let tiny_ops: RichTextDelta = DeltaBuilder::new()
+        .retain_with_attributes(1, italic.clone())
+        .insert("x")
+        .delete(1)
+        .retain_with_attributes(2, italic)
+        .delete(1)
+        .retain(3)
+        .build();
+
+    let document: RichTextDelta = DeltaBuilder::new()
+        .insert_with_attributes("abcdefgh", bold.clone())
+        .build();
+    assert_eq!(
+        document.compose(&tiny_ops).unwrap(),
+        DeltaBuilder::new()
+            .insert_with_attributes("a", bold_italic.clone())
+            .insert("x")
+            .insert_with_attributes("cd", bold_italic.clone())
+            .insert_with_attributes("fgh", bold.clone())
+            .build()
+    );
+
+    let retain: RichTextDelta = DeltaBuilder::new().retain_with_attributes(8, bold.clone()).build();
+    assert_eq!(
+        retain.compose(&tiny_ops).unwrap(),
+        DeltaBuilder::new()
+            .retain_with_attributes(1, bold_italic.clone())
+            .insert("x")
+            .delete(1)
+            .retain_with_attributes(2, bold_italic)
+            .delete(1)
+            .retain_with_attributes(3, bold)
+            .build()
+    );
+}
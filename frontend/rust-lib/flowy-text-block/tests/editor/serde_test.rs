@@ -1,8 +1,13 @@
+use crate::editor::Rng;
 use flowy_sync::client_document::{ClientDocument, PlainDoc};
 use lib_ot::rich_text::RichTextOperation;
 use lib_ot::{
     core::*,
-    rich_text::{AttributeBuilder, RichTextAttribute, RichTextAttributeValue, RichTextDelta},
+    errors::OTErrorCode,
+    rich_text::{
+        AttributeBuilder, RichTextAttribute, RichTextAttributeKey, RichTextAttributeValue, RichTextAttributes,
+        RichTextDelta,
+    },
 };
 
 #[test]
@@ -89,17 +94,82 @@ fn delta_deserialize_null_test() {
     let delta1 = RichTextDelta::from_delta_str(json).unwrap();
 
     let mut attribute = RichTextAttribute::Bold(true);
-    attribute.value = RichTextAttributeValue(None);
+    attribute.value = RichTextAttributeValue::Null;
     let delta2 = DeltaBuilder::new().retain_with_attributes(7, attribute.into()).build();
 
     assert_eq!(delta2.to_delta_str(), r#"[{"retain":7,"attributes":{"bold":""}}]"#);
     assert_eq!(delta1, delta2);
 }
 
+#[test]
+fn delta_typed_attribute_value_serde_test() {
+    let json = r#"[{"insert":"123","attributes":{"bold":false,"header":2,"color":"red","italic":""}}]"#;
+    let delta = RichTextDelta::from_delta_str(json).unwrap();
+    let attributes = delta.ops[0].get_attributes();
+    assert_eq!(
+        attributes.get(&RichTextAttributeKey::Bold),
+        Some(&RichTextAttributeValue::Bool(false))
+    );
+    assert_eq!(
+        attributes.get(&RichTextAttributeKey::Header),
+        Some(&RichTextAttributeValue::Int(2))
+    );
+    assert_eq!(
+        attributes.get(&RichTextAttributeKey::Color),
+        Some(&RichTextAttributeValue::Str("red".to_owned()))
+    );
+    assert_eq!(
+        attributes.get(&RichTextAttributeKey::Italic),
+        Some(&RichTextAttributeValue::Null)
+    );
+    assert_eq!(RichTextDelta::from_delta_str(&delta.to_delta_str()).unwrap(), delta);
+    assert_eq!(
+        RichTextDelta::from_compact_bytes(&delta.to_compact_bytes()).unwrap(),
+        delta
+    );
+
+    let value: serde_json::Value = serde_json::from_str(&delta.to_delta_str()).unwrap();
+    assert_eq!(value[0]["attributes"]["bold"], serde_json::json!(false));
+    assert_eq!(value[0]["attributes"]["header"], serde_json::json!(2));
+
+    // The strings of the older JSON are read as the type of their keys.
+    let legacy =
+        RichTextDelta::from_delta_str(r#"[{"insert":"123","attributes":{"bold":"true","header":"1"}}]"#).unwrap();
+    let expected = DeltaBuilder::new()
+        .insert_with_attributes(
+            "123",
+            AttributeBuilder::new()
+                .add_attr(RichTextAttribute::Bold(true))
+                .add_attr(RichTextAttribute::Header(1))
+                .build(),
+        )
+        .build();
+    assert_eq!(legacy, expected);
+
+    // The font and the size are the names rather than the numbers, even if they look like ones.
+    let delta = RichTextDelta::from_delta_str(
+        r#"[{"insert":"123","attributes":{"font":"monospace","size":"large"}},{"insert":"4","attributes":{"size":"12"}}]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        delta.ops[0].get_attributes().get(&RichTextAttributeKey::Font),
+        Some(&RichTextAttributeValue::Str("monospace".to_owned()))
+    );
+    assert_eq!(
+        delta.ops[0].get_attributes().get(&RichTextAttributeKey::Size),
+        Some(&RichTextAttributeValue::Str("large".to_owned()))
+    );
+    assert_eq!(
+        delta.ops[1].get_attributes().get(&RichTextAttributeKey::Size),
+        Some(&RichTextAttributeValue::Str("12".to_owned()))
+    );
+    assert_eq!(RichTextDelta::from_delta_str(&delta.to_delta_str()).unwrap(), delta);
+}
+
 #[test]
 fn delta_serde_null_test() {
     let mut attribute = RichTextAttribute::Bold(true);
-    attribute.value = RichTextAttributeValue(None);
+    attribute.value = RichTextAttributeValue::Null;
     assert_eq!(attribute.to_json(), r#"{"bold":""}"#);
 }
 
@@ -115,3 +185,261 @@ fn document_insert_serde_test() {
         ClientDocument::from_json(&json).unwrap().delta_str()
     );
 }
+
+#[test]
+fn delta_embed_serde_test() {
+    let image = Embed::new("image", "https://appflowy.io/logo.png");
+    let attributes = AttributeBuilder::new().add_attr(RichTextAttribute::Width(100)).build();
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .insert("a")
+        .insert_embed(image, attributes)
+        .insert("b")
+        .build();
+    let json = delta.to_delta_str();
+    assert_eq!(
+        json,
+        r#"[{"insert":"a"},{"insert":{"image":"https://appflowy.io/logo.png"},"attributes":{"width":100}},{"insert":"b"}]"#
+    );
+    assert_eq!(RichTextDelta::from_delta_str(&json).unwrap(), delta);
+}
+
+#[test]
+fn delta_embed_with_object_value_serde_test() {
+    let mention = Embed::new("mention", serde_json::json!({"id": "1", "name": "nathan"}));
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .insert("a")
+        .insert_embed(mention, RichTextAttributes::default())
+        .insert("b")
+        .build();
+    assert_eq!(delta.utf16_target_len, 3);
+    let json = delta.to_delta_str();
+    assert_eq!(
+        json,
+        r#"[{"insert":"a"},{"insert":{"mention":{"id":"1","name":"nathan"}}},{"insert":"b"}]"#
+    );
+    assert_eq!(RichTextDelta::from_delta_str(&json).unwrap(), delta);
+    assert_eq!(
+        RichTextDelta::from_compact_bytes(&delta.to_compact_bytes()).unwrap(),
+        delta
+    );
+}
+
+#[test]
+fn delta_quill_serde_test() {
+    let json = r#"{"ops":[{"insert":"123","attributes":{"bold":true}},{"retain":2},{"delete":1}]}"#;
+    let delta = RichTextDelta::from_delta_str(json).unwrap();
+    assert_eq!(delta.ops.len(), 3);
+    assert_eq!(delta.to_quill_delta_str(), json);
+    assert_eq!(
+        delta,
+        RichTextDelta::from_delta_str(r#"[{"insert":"123","attributes":{"bold":true}},{"retain":2},{"delete":1}]"#)
+            .unwrap()
+    );
+}
+
+#[test]
+fn attributes_native_json_value_serde_test() {
+    let json = r#"{"bold":true,"header":1,"color":"red"}"#;
+    let attributes: RichTextAttributes = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        attributes.get(&RichTextAttributeKey::Bold).unwrap().as_bool(),
+        Some(true)
+    );
+    assert_eq!(
+        attributes.get(&RichTextAttributeKey::Header).unwrap().as_usize(),
+        Some(1)
+    );
+    assert_eq!(
+        attributes.get(&RichTextAttributeKey::Color).unwrap().as_str(),
+        Some("red")
+    );
+
+    let value = serde_json::to_value(&attributes).unwrap();
+    assert_eq!(value, serde_json::from_str::<serde_json::Value>(json).unwrap());
+}
+
+#[test]
+fn delta_from_reader_test() {
+    let mut rng = Rng::default();
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut delta = RichTextDelta::default();
+    for i in 0..10_000 {
+        let s = rng.gen_string(10);
+        match i % 2 {
+            0 => delta.insert(&s, RichTextAttributes::default()),
+            _ => delta.insert(&s, bold.clone()),
+        }
+    }
+    let json = delta.to_delta_str();
+    let streamed = RichTextDelta::from_reader(json.as_bytes()).unwrap();
+    assert_eq!(streamed, RichTextDelta::from_delta_str(&json).unwrap());
+    assert_eq!(streamed, delta);
+
+    let quill_json = delta.to_quill_delta_str();
+    assert_eq!(RichTextDelta::from_reader(quill_json.as_bytes()).unwrap(), delta);
+
+    assert!(RichTextDelta::from_reader(r#"[{"insert":"123"}"#.as_bytes()).is_err());
+}
+
+#[test]
+fn delta_compact_bytes_serde_test() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let unbold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(false)).build();
+    let header = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Header(1))
+        .add_attr(RichTextAttribute::Color("#ff0000".to_owned()))
+        .build();
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .retain_with_attributes(3, unbold)
+        .insert("你好😁")
+        .insert_with_attributes("bold", bold.clone())
+        .insert_embed(Embed::new("image", "https://appflowy.io/logo.png"), bold)
+        .insert_with_attributes("\n", header)
+        .retain(200)
+        .delete(1000)
+        .build();
+    let bytes = delta.to_compact_bytes();
+    assert_eq!(RichTextDelta::from_compact_bytes(&bytes).unwrap(), delta);
+
+    assert!(RichTextDelta::from_compact_bytes(&bytes[..bytes.len() - 1]).is_err());
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(RichTextDelta::from_compact_bytes(&trailing).is_err());
+
+    // The ints use all 64 bits whatever the pointer width is.
+    let sizes: RichTextAttributes = vec![
+        (RichTextAttributeKey::Width, RichTextAttributeValue::Int(i64::MIN)),
+        (RichTextAttributeKey::Height, RichTextAttributeValue::Int(i64::MAX)),
+        (RichTextAttributeKey::Indent, RichTextAttributeValue::Int(-1)),
+    ]
+    .into_iter()
+    .collect();
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .insert_embed(Embed::new("image", "https://appflowy.io/logo.png"), sizes)
+        .build();
+    assert_eq!(
+        RichTextDelta::from_compact_bytes(&delta.to_compact_bytes()).unwrap(),
+        delta
+    );
+
+    // No keys, one delete whose varint sets the bits beyond the 64th in its tenth byte.
+    let mut overflow = vec![0, 1, 3];
+    overflow.extend_from_slice(&[0xff; 9]);
+    overflow.push(0x02);
+    assert!(RichTextDelta::from_compact_bytes(&overflow).is_err());
+}
+
+#[test]
+fn delta_compact_bytes_size_test() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let italic = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Italic(true))
+        .build();
+    let mut delta = RichTextDelta::default();
+    for i in 0..100 {
+        match i % 2 {
+            0 => delta.insert("hello world ", bold.clone()),
+            _ => delta.insert("hello world ", italic.clone()),
+        }
+    }
+    let bytes = delta.to_compact_bytes();
+    let json = delta.to_delta_str();
+    assert!(
+        bytes.len() * 2 < json.len(),
+        "compact: {}, json: {}",
+        bytes.len(),
+        json.len()
+    );
+    assert_eq!(RichTextDelta::from_compact_bytes(&bytes).unwrap(), delta);
+}
+
+#[test]
+fn delta_deserialize_malformed_operation() {
+    let json = r#"[{"insert":"123"},{"retain":1},{"foo":1},{"delete":1}]"#;
+    let err = RichTextDelta::from_delta_str(json).unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::SerdeError));
+    assert!(err.msg.contains("Invalid operation at index 2"), "{}", err.msg);
+    assert!(err.msg.contains("unknown field `foo`"), "{}", err.msg);
+    assert!(err.msg.contains("column"), "{}", err.msg);
+
+    let json = r#"{"ops":[{"insert":"123"},{"retain":"1"}]}"#;
+    let err = RichTextDelta::from_delta_str(json).unwrap_err();
+    assert!(err.msg.contains("Invalid operation at index 1"), "{}", err.msg);
+
+    // The invalid JSON only tells the position.
+    let err = RichTextDelta::from_delta_str(r#"[{"insert":"123"},"#).unwrap_err();
+    assert!(!err.msg.contains("index"), "{}", err.msg);
+    assert!(err.msg.contains("column"), "{}", err.msg);
+}
+
+#[test]
+fn delta_deserialize_empty_attributes() {
+    let op: RichTextOperation = serde_json::from_str(r#"{"retain":3,"attributes":{}}"#).unwrap();
+    assert!(op.is_noop());
+    assert_eq!(op, OpBuilder::retain(3).build());
+
+    // The retain with the empty attributes merges with the plain one and is chopped like it.
+    let json = r#"[{"retain":3,"attributes":{}},{"retain":2},{"insert":"a"},{"retain":4,"attributes":{}}]"#;
+    let mut delta = RichTextDelta::from_delta_str(json).unwrap();
+    assert_eq!(delta, DeltaBuilder::new().retain(5).insert("a").retain(4).build());
+    delta.chop();
+    assert_eq!(delta.ops, DeltaBuilder::new().retain(5).insert("a").build().ops);
+
+    // So does the one pushed to the operations directly once the delta is normalized.
+    let mut delta = RichTextDelta::default();
+    delta.ops.push(OpBuilder::retain(2).build());
+    delta.ops.push(op);
+    delta.normalize();
+    assert_eq!(delta.ops, vec![OpBuilder::retain(5).build()]);
+    delta.chop();
+    assert!(delta.ops.is_empty());
+}
+
+#[test]
+fn delta_from_quill_ops() {
+    let value = serde_json::json!({
+        "ops": [
+            {"insert": "123", "attributes": {"bold": true, "size": 12.5, "foo": "bar"}},
+            {"insert": {"image": "https://appflowy.io/logo.png"}},
+            {"insert": "\n", "attributes": {"header": "1"}},
+            {"retain": "2"},
+            {"delete": 1}
+        ]
+    });
+    let delta = RichTextDelta::from_quill_ops(value).unwrap();
+    let mut attributes: RichTextAttributes = RichTextAttribute::Bold(true).into();
+    attributes.insert(
+        RichTextAttributeKey::Size,
+        RichTextAttributeValue::Str("12.5".to_owned()),
+    );
+    attributes.insert(
+        RichTextAttributeKey::Custom("foo".to_owned()),
+        RichTextAttributeValue::Str("bar".to_owned()),
+    );
+    let expected = DeltaBuilder::new()
+        .insert_with_attributes("123", attributes)
+        .insert_embed(
+            Embed::new("image", "https://appflowy.io/logo.png"),
+            RichTextAttributes::default(),
+        )
+        .insert_with_attributes("\n", RichTextAttribute::Header(1).into())
+        .retain(2)
+        .delete(1)
+        .build();
+    assert_eq!(delta, expected);
+
+    // The unknown key survives the round-trip through the Quill format and the saved formats.
+    let quill_json = delta.to_quill_delta_str();
+    assert!(quill_json.contains(r#""foo":"bar""#), "{}", quill_json);
+    let value: serde_json::Value = serde_json::from_str(&quill_json).unwrap();
+    assert_eq!(RichTextDelta::from_quill_ops(value).unwrap(), delta);
+    assert_eq!(RichTextDelta::from_bytes(delta.to_delta_bytes()).unwrap(), delta);
+    assert_eq!(
+        RichTextDelta::from_compact_bytes(&delta.to_compact_bytes()).unwrap(),
+        delta
+    );
+
+    let err = RichTextDelta::from_quill_ops(serde_json::json!([{"insert": "1"}, {"retain": -1}])).unwrap_err();
+    assert!(matches!(err.code, OTErrorCode::SerdeError));
+    assert!(err.msg.contains("Invalid operation at index 1"), "{}", err.msg);
+}
@@ -0,0 +1,14 @@
+#![cfg_attr(rustfmt, rustfmt::skip)]
+// Auto-generated, do not edit
+
+mod ws_data;
+pub use ws_data::*;
+
+mod folder;
+pub use folder::*;
+
+mod text_block;
+pub use text_block::*;
+
+mod revision;
+pub use revision::*;
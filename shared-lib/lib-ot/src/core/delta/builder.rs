@@ -1,4 +1,4 @@
-use crate::core::{trim, Attributes, Delta, PlainTextAttributes};
+use crate::core::{trim, Attributes, Delta, Embed, PlainTextAttributes};
 
 pub type PlainTextDeltaBuilder = DeltaBuilder<PlainTextAttributes>;
 
@@ -6,7 +6,7 @@ pub struct DeltaBuilder<T: Attributes> {
     delta: Delta<T>,
 }
 
-impl<T> std::default::Default for DeltaBuilder<T>
+impl<T> core::default::Default for DeltaBuilder<T>
 where
     T: Attributes,
 {
@@ -23,6 +23,15 @@ where
         DeltaBuilder::default()
     }
 
+    /// Creates the builder with the room for `capacity` operations, so building a large delta,
+    /// e.g. importing a long document, doesn't grow the operations again and again. The adjacent
+    /// operations may be merged, so it's only a hint.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            delta: Delta::with_capacity(capacity),
+        }
+    }
+
     pub fn retain_with_attributes(mut self, n: usize, attrs: T) -> Self {
         self.delta.retain(n, attrs);
         self
@@ -48,6 +57,11 @@ where
         self
     }
 
+    pub fn insert_embed(mut self, embed: Embed, attrs: T) -> Self {
+        self.delta.insert_embed(embed, attrs);
+        self
+    }
+
     pub fn trim(mut self) -> Self {
         trim(&mut self.delta);
         self
@@ -3,7 +3,8 @@ use crate::{
     core::{Attributes, Delta, Interval, Operation},
     errors::{ErrorBuilder, OTError, OTErrorCode},
 };
-use std::{cmp::min, iter::Enumerate, slice::Iter};
+use alloc::format;
+use core::{cmp::min, iter::Enumerate, slice::Iter};
 
 #[derive(Debug)]
 pub struct OpCursor<'a, T: Attributes> {
@@ -13,7 +14,11 @@ pub struct OpCursor<'a, T: Attributes> {
     pub(crate) consume_count: usize,
     pub(crate) op_index: usize,
     iter: Enumerate<Iter<'a, Operation<T>>>,
-    next_op: Option<Operation<T>>,
+    // The operation that is partially consumed and the length consumed from it. The operation is
+    // never split to keep the rest, the cursor moves within it by the offset instead, so the long
+    // retain consumed by many small operations isn't rebuilt every step.
+    next_op: Option<&'a Operation<T>>,
+    next_op_offset: usize,
 }
 
 impl<'a, T> OpCursor<'a, T>
@@ -30,6 +35,7 @@ where
             op_index: 0,
             iter: delta.ops.iter().enumerate(),
             next_op: None,
+            next_op_offset: 0,
         };
         cursor.descend(0);
         cursor
@@ -48,31 +54,27 @@ where
     // checkout the delta_next_op_with_len_cross_op_return_last test for more detail
     pub fn next_with_len(&mut self, expected_len: Option<usize>) -> Option<Operation<T>> {
         let mut find_op = None;
-        let holder = self.next_op.clone();
-        let mut next_op = holder.as_ref();
-
+        let mut next_op = self.next_op.take().map(|op| (op, self.next_op_offset));
+        self.next_op_offset = 0;
         if next_op.is_none() {
-            next_op = find_next(self);
+            next_op = find_next(self).map(|op| (op, 0));
         }
 
         let mut consume_len = 0;
         while find_op.is_none() && next_op.is_some() {
-            let op = next_op.take().unwrap();
-            let interval = self
-                .next_iv_with_len(expected_len)
-                .unwrap_or_else(|| Interval::new(0, 0));
+            let (op, offset) = next_op.take().unwrap();
+            let interval = self.op_iv_with_len(op.len() - offset, expected_len);
 
             // cache the op if the interval is empty. e.g. last_op_before(Some(0))
             if interval.is_empty() {
-                self.next_op = Some(op.clone());
+                self.next_op = Some(op);
+                self.next_op_offset = offset;
                 break;
             }
-            find_op = op.shrink(interval);
-            let suffix = Interval::new(0, op.len()).suffix(interval);
-            if suffix.is_empty() {
-                self.next_op = None;
-            } else {
-                self.next_op = op.shrink(suffix);
+            find_op = op.shrink(interval.translate(offset));
+            if offset + interval.end < op.len() {
+                self.next_op = Some(op);
+                self.next_op_offset = offset + interval.end;
             }
 
             consume_len += interval.end;
@@ -81,7 +83,7 @@ where
 
             // continue to find the op in next iteration
             if find_op.is_none() {
-                next_op = find_next(self);
+                next_op = find_next(self).map(|op| (op, 0));
             }
         }
 
@@ -114,7 +116,8 @@ where
             if intersect.is_empty() {
                 self.consume_count += op.len();
             } else {
-                self.next_op = Some(op.clone());
+                self.next_op = Some(op);
+                self.next_op_offset = 0;
                 break;
             }
         }
@@ -122,30 +125,26 @@ where
 
     fn next_iv_with_len(&self, expected_len: Option<usize>) -> Option<Interval> {
         let op = self.next_iter_op()?;
+        Some(self.op_iv_with_len(op.len() - self.next_op_offset, expected_len))
+    }
+
+    // Returns the interval of the next `op_len` to consume, relative to the consumed position.
+    fn op_iv_with_len(&self, op_len: usize, expected_len: Option<usize>) -> Interval {
         let start = self.consume_count;
         let end = match expected_len {
-            None => self.consume_count + op.len(),
-            Some(expected_len) => self.consume_count + min(expected_len, op.len()),
+            None => self.consume_count + op_len,
+            Some(expected_len) => self.consume_count + min(expected_len, op_len),
         };
 
         let intersect = Interval::new(start, end).intersect(self.consume_iv);
-        let interval = intersect.translate_neg(start);
-        Some(interval)
+        intersect.translate_neg(start)
     }
 
+    // Returns the whole operation that the next one is cut from, it's only meant to tell the type
+    // of the next operation. The pending operation is peeked from the iterator without rescanning
+    // the delta.
     pub fn next_iter_op(&self) -> Option<&Operation<T>> {
-        let mut next_op = self.next_op.as_ref();
-        if next_op.is_none() {
-            let mut offset = 0;
-            for op in &self.delta.ops {
-                offset += op.len();
-                if offset > self.consume_count {
-                    next_op = Some(op);
-                    break;
-                }
-            }
-        }
-        next_op
+        self.next_op.or_else(|| self.iter.clone().next().map(|(_, op)| op))
     }
 }
 
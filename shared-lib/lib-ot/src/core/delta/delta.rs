@@ -1,28 +1,104 @@
 use crate::{
-    core::{operation::*, DeltaIter, FlowyStr, Interval, OperationTransformable, MAX_IV_LEN},
+    core::{
+        count_utf16_code_units, operation::*, DeltaIter, FlowyStr, Interval, OperationTransformable, StrInterner,
+        Utf16CodeUnitIterator, MAX_IV_LEN, NEW_LINE,
+    },
     errors::{ErrorBuilder, OTError, OTErrorCode},
 };
 
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeSet,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use bytes::Bytes;
-use serde::de::DeserializeOwned;
-use std::{
-    cmp::{min, Ordering},
+use core::{
+    cmp::{max, min, Ordering},
     fmt,
     iter::FromIterator,
     str,
     str::FromStr,
 };
+#[cfg(feature = "std")]
+use ropey::Rope;
+use serde::de::DeserializeOwned;
+#[cfg(feature = "std")]
+use similar::{capture_diff_slices, Algorithm, DiffTag};
 
 pub type PlainTextDelta = Delta<PlainTextAttributes>;
 
 // TODO: optimize the memory usage with Arc::make_mut or Cow
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Delta<T: Attributes> {
     pub ops: Vec<Operation<T>>,
     pub utf16_base_len: usize,
     pub utf16_target_len: usize,
 }
 
+/// The operation reported by `Delta::apply_with` when it's applied. The intervals and indexes
+/// are counted in UTF-16 code units of the output string.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ApplyEvent<'a, T: Attributes> {
+    /// The text at the `interval` is kept, and formatted if the `attributes` aren't empty.
+    Retain { interval: Interval, attributes: &'a T },
+    /// The string `s` is inserted at the `interval`.
+    Insert {
+        interval: Interval,
+        s: &'a str,
+        attributes: &'a T,
+    },
+    /// The `n` code units of the input are removed at the `index`.
+    Delete { index: usize, n: usize },
+}
+
+/// The checksum of the string that a delta is based on, i.e. the document the delta was created
+/// against. It's sent along with the delta, so `Delta::apply_checked` can refuse to apply the delta
+/// to another document, e.g. when the delta is applied twice or out of order, which the length
+/// check alone can't catch.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct BaseChecksum {
+    pub utf16_len: usize,
+    pub md5: String,
+}
+
+impl BaseChecksum {
+    pub fn new(s: &str) -> Self {
+        Self {
+            utf16_len: count_utf16_code_units(s),
+            md5: format!("{:x}", md5::compute(s)),
+        }
+    }
+}
+
+/// Decides which of the two concurrent deltas goes first when both of them insert at the same
+/// position, i.e. the `priority` of `transform_with_priority`. `Left` means the delta goes first
+/// and `Right` means `other` goes first.
+///
+/// The flag only works for two editors. With more editors, every pair of them must agree on the
+/// order, so `ById` gives every editor a unique site id and the delta of the smaller id goes
+/// first. The inserts at the same position then end up in the order of the site ids on every
+/// editor, no matter in which order the deltas arrive.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TieBreak {
+    Left,
+    Right,
+    ById { site_id: u64, other_site_id: u64 },
+}
+
+impl TieBreak {
+    /// Returns true if the delta goes first.
+    pub fn priority(&self) -> bool {
+        match self {
+            TieBreak::Left => true,
+            TieBreak::Right => false,
+            TieBreak::ById { site_id, other_site_id } => site_id <= other_site_id,
+        }
+    }
+}
+
 impl<T> Default for Delta<T>
 where
     T: Attributes,
@@ -81,10 +157,25 @@ where
         }
     }
 
+    /// The length of the document that the delta can be applied to, in UTF-16 code units.
+    #[inline]
+    pub fn base_len(&self) -> usize {
+        self.utf16_base_len
+    }
+
+    /// The length of the document after applying the delta, in UTF-16 code units.
+    #[inline]
+    pub fn target_len(&self) -> usize {
+        self.utf16_target_len
+    }
+
     pub fn add(&mut self, op: Operation<T>) {
         match op {
             Operation::Delete(i) => self.delete(i),
-            Operation::Insert(i) => self.insert(&i.s, i.attributes),
+            Operation::Insert(i) => match i.embed {
+                None => self.insert(&i.s, i.attributes),
+                Some(embed) => self.insert_embed(embed, i.attributes),
+            },
             Operation::Retain(r) => self.retain(r.n, r.attributes),
         }
     }
@@ -114,8 +205,12 @@ where
                 insert.merge_or_new_op(&s, attributes)
             }
             [.., Operation::<T>::Insert(pre_insert), Operation::Delete(_)] => {
-                //
-                pre_insert.merge_or_new_op(&s, attributes)
+                // Keep the insert before the delete if it can't be merged with the previous one.
+                if let Some(new_op) = pre_insert.merge_or_new_op(&s, attributes) {
+                    let delete_index = self.ops.len() - 1;
+                    self.ops.insert(delete_index, new_op);
+                }
+                None
             }
             [.., op_last @ Operation::<T>::Delete(_)] => {
                 let new_last = op_last.clone();
@@ -131,6 +226,19 @@ where
         }
     }
 
+    /// Inserts the embed that counts as one code unit. It never merges with the adjacent inserts.
+    pub fn insert_embed(&mut self, embed: Embed, attributes: T) {
+        self.utf16_target_len += 1;
+        let new_op = OpBuilder::<T>::embed(embed).attributes(attributes).build();
+        match self.ops.as_mut_slice() {
+            [.., op_last @ Operation::<T>::Delete(_)] => {
+                let new_last = core::mem::replace(op_last, new_op);
+                self.ops.push(new_last);
+            }
+            _ => self.ops.push(new_op),
+        }
+    }
+
     pub fn retain(&mut self, n: usize, attributes: T) {
         if n == 0 {
             return;
@@ -147,34 +255,521 @@ where
         }
     }
 
+    /// Rebuilds the delta from its operations, e.g. the operations pushed to `ops` directly. The
+    /// adjacent operations of the same kind and attributes are merged, inserts are moved before
+    /// the deletes, the empty operations are dropped and the lengths are recomputed.
+    ///
+    /// The inserts and deletes between two retains happen at the same position, so they are sorted
+    /// by `Operation::canonical_cmp` first. The retains are never moved.
+    pub fn normalize(&mut self) {
+        let ops = core::mem::take(&mut self.ops);
+        let mut normalized = Delta::with_capacity(ops.len());
+        let mut run = vec![];
+        for op in ops {
+            if op.is_retain() {
+                add_sorted_run(&mut normalized, &mut run);
+                normalized.add(op);
+            } else {
+                run.push(op);
+            }
+        }
+        add_sorted_run(&mut normalized, &mut run);
+        *self = normalized;
+    }
+
+    /// Normalizes the delta and releases the unused capacity of the operations and the inserted
+    /// strings, e.g. the delta kept in memory after a long editing session, in which the strings
+    /// grow by the merged inserts.
+    ///
+    /// The equal attributes are interned: the first ones of every format are shared by
+    /// `Attributes::share` and the rest take their clones, so the operations with the same format,
+    /// e.g. the runs of the bold text, keep one copy of it. The distinct formats of a document are
+    /// few, so they are looked up linearly.
+    pub fn compact(&mut self) {
+        self.normalize();
+        let mut interned: Vec<T> = vec![];
+        for op in self.ops.iter_mut() {
+            let attributes = match op {
+                Operation::Insert(insert) => {
+                    insert.s.shrink_to_fit();
+                    &mut insert.attributes
+                }
+                Operation::Retain(retain) => &mut retain.attributes,
+                Operation::Delete(_) => continue,
+            };
+            if attributes.is_empty() {
+                continue;
+            }
+            match interned.iter().find(|interned| *interned == attributes) {
+                Some(interned) => *attributes = interned.clone(),
+                None => {
+                    attributes.share();
+                    interned.push(attributes.clone());
+                }
+            }
+        }
+        self.ops.shrink_to_fit();
+    }
+
+    /// Replaces the text of every insert with the shared string of the `interner`, so the
+    /// identical inserts, e.g. the spaces between the words, share one allocation. The interned
+    /// delta applies, composes and transforms like the original one. Splitting or merging an
+    /// interned insert produces a new owned string.
+    pub fn intern(&mut self, interner: &mut StrInterner) {
+        for op in self.ops.iter_mut() {
+            if let Operation::Insert(insert) = op {
+                insert.s = interner.intern(&insert.s);
+            }
+        }
+    }
+
+    /// Rewrites the attributes of every operation with `f`, e.g. to rename an attribute key or to
+    /// clamp the header levels when the schema changes. The plain operations and the deletes are
+    /// skipped. Like `compose`, the removed values left on the inserts are dropped, and the
+    /// delta is normalized afterwards because the operations with the same new attributes merge.
+    pub fn map_attributes<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for op in self.ops.iter_mut() {
+            match op {
+                Operation::Retain(retain) if !retain.attributes.is_empty() => f(&mut retain.attributes),
+                Operation::Insert(insert) if !insert.attributes.is_empty() => {
+                    f(&mut insert.attributes);
+                    insert.attributes.remove_empty();
+                }
+                _ => {}
+            }
+        }
+        self.normalize();
+    }
+
+    /// Builds the document delta from the plain text, e.g. the text pasted from the outside. The
+    /// line endings `\r\n` and `\r` are normalized to `\n`, which the line based block attributes
+    /// rely on.
+    pub fn from_plain_text(s: &str) -> Self {
+        let mut delta = Delta::new();
+        delta.insert(&s.replace("\r\n", NEW_LINE).replace('\r', NEW_LINE), T::default());
+        delta
+    }
+
+    /// Builds the document delta from the lines and their block attributes, e.g. the pasted bullet
+    /// list. Like Quill, the block attributes are put on the newline that ends each line, and the
+    /// text of the line is inserted without attributes. If the text contains newlines, every part of
+    /// it becomes a line with the same block attributes.
+    pub fn from_lines(lines: &[(String, T)]) -> Self {
+        let mut delta = Delta::new();
+        for (text, attributes) in lines {
+            for line in text.split(NEW_LINE) {
+                delta.insert(line, T::default());
+                delta.insert(NEW_LINE, attributes.clone());
+            }
+        }
+        delta
+    }
+
+    /// Builds the delta that inserts `s` at the `index` of the document of `base_len`. The default
+    /// attributes are used if `attributes` is `None`.
+    pub fn insert_at(index: usize, s: &str, attributes: Option<T>, base_len: usize) -> Result<Self, OTError> {
+        if index > base_len {
+            return Err(ErrorBuilder::new(OTErrorCode::IntervalOutOfBound)
+                .msg(format!("The index {} is greater than the length {}", index, base_len))
+                .build());
+        }
+        let mut delta = Delta::new();
+        delta.retain(index, T::default());
+        delta.insert(s, attributes.unwrap_or_default());
+        delta.retain(base_len - index, T::default());
+        Ok(delta)
+    }
+
+    /// Builds the delta that deletes the range [start, end) of the document of `base_len`.
+    pub fn delete_range(start: usize, end: usize, base_len: usize) -> Result<Self, OTError> {
+        if start > end || end > base_len {
+            return Err(ErrorBuilder::new(OTErrorCode::IntervalOutOfBound)
+                .msg(format!(
+                    "The range [{}, {}) is out of the length {}",
+                    start, end, base_len
+                ))
+                .build());
+        }
+        let mut delta = Delta::new();
+        delta.retain(start, T::default());
+        delta.delete(end - start);
+        delta.retain(base_len - end, T::default());
+        Ok(delta)
+    }
+
+    /// Builds the delta that replaces the range [start, end) of the document of `base_len` with
+    /// `s`, e.g. accepting the autocompletion. The `attributes` only apply to `s`, the default
+    /// attributes are used if it's `None`. Like every insert, `s` is put before the delete.
+    pub fn replace(start: usize, end: usize, s: &str, attributes: Option<T>, base_len: usize) -> Result<Self, OTError> {
+        if start > end || end > base_len {
+            return Err(ErrorBuilder::new(OTErrorCode::IntervalOutOfBound)
+                .msg(format!(
+                    "The range [{}, {}) is out of the length {}",
+                    start, end, base_len
+                ))
+                .build());
+        }
+        let mut delta = Delta::new();
+        delta.retain(start, T::default());
+        delta.delete(end - start);
+        delta.insert(s, attributes.unwrap_or_default());
+        delta.retain(base_len - end, T::default());
+        Ok(delta)
+    }
+
+    /// Builds the delta that formats the range [start, end) of the document delta with the
+    /// `attributes`, the rest of the document is retained as it is. The attributes are routed by
+    /// `Attributes::split_block`: the block attributes, e.g. the header, format the newlines that
+    /// end the lines touched by the range, or the line of `start` if the range is empty, and the
+    /// rest format the range itself. The last line without the newline can't carry the block
+    /// attributes, so they are dropped for it.
+    pub fn format(&self, start: usize, end: usize, attributes: T) -> Result<Self, OTError> {
+        let base_len = self.utf16_target_len;
+        if start > end || end > base_len {
+            return Err(ErrorBuilder::new(OTErrorCode::IntervalOutOfBound)
+                .msg(format!(
+                    "The range [{}, {}) is out of the length {}",
+                    start, end, base_len
+                ))
+                .build());
+        }
+        let (block, inline) = attributes.split_block();
+        let line_ends = match block.is_empty() {
+            true => vec![],
+            false => self.line_ends(start, end),
+        };
+
+        // The attributes change at the bounds of the range and around every newline of the lines.
+        let mut bounds = BTreeSet::from_iter(vec![0, start, end, base_len]);
+        line_ends.iter().for_each(|index| {
+            bounds.insert(*index);
+            bounds.insert(*index + 1);
+        });
+        let mut line_ends = line_ends.into_iter().peekable();
+        let mut delta = Delta::new();
+        for (from, to) in bounds.iter().zip(bounds.iter().skip(1)) {
+            let mut attributes = match start <= *from && *to <= end {
+                true => inline.clone(),
+                false => T::default(),
+            };
+            if line_ends.peek() == Some(from) {
+                line_ends.next();
+                attributes.extend_other(block.clone());
+            }
+            delta.retain(to - from, attributes);
+        }
+        Ok(delta)
+    }
+
+    // Returns the indexes of the newlines that end the lines touched by [start, end), or the line
+    // of `start` if the range is empty.
+    fn line_ends(&self, start: usize, end: usize) -> Vec<usize> {
+        let last = max(end, start + 1);
+        let mut line_ends = vec![];
+        let mut index = 0;
+        let mut line_start = 0;
+        for op in &self.ops {
+            match op {
+                Operation::Insert(insert) if insert.embed.is_none() => {
+                    for c in insert.s.chars() {
+                        if c == '\n' {
+                            if index >= start && line_start < last {
+                                line_ends.push(index);
+                            }
+                            line_start = index + 1;
+                        }
+                        index += c.len_utf16();
+                    }
+                }
+                Operation::Delete(_) => {}
+                _ => index += op.len(),
+            }
+            if line_start >= last {
+                break;
+            }
+        }
+        line_ends
+    }
+
+    /// Appends the operations of `other` after the operations of the delta. Unlike `compose`, the
+    /// operations of `other` aren't applied to the result of the delta. The operations at the
+    /// seam are merged if possible and the lengths are summed up.
+    pub fn concat(&self, other: &Self) -> Self {
+        let mut delta = Delta::with_capacity(self.ops.len() + other.ops.len());
+        self.ops
+            .iter()
+            .chain(other.ops.iter())
+            .for_each(|op| delta.add(op.clone()));
+        delta
+    }
+
+    /// Returns true if the operations of the delta are the beginning of the operations of `other`,
+    /// e.g. the document of a reconnecting client that the server has only appended to. See
+    /// `suffix_after` for the last operation that is shared partially.
+    pub fn is_prefix_of(&self, other: &Self) -> bool {
+        self.suffix_after(other).is_some()
+    }
+
+    /// Returns the operations of `other` after the ones of the delta if the delta is its prefix, so
+    /// `self.concat(&suffix) == *other`, otherwise returns `None`.
+    ///
+    /// The adjacent operations are merged when they are added, so typing at the end of the document
+    /// grows its last insert. That's why the last operation of the delta counts as shared if it's
+    /// the beginning of the operation of `other` at the same place, e.g. the insert "ab" of
+    /// "abc" or the retain 3 of the retain 5 with the same attributes, and the rest of that
+    /// operation starts the suffix.
+    pub fn suffix_after(&self, other: &Self) -> Option<Self> {
+        let n = match self.ops.len() {
+            0 => return Some(other.clone()),
+            len if len > other.ops.len() => return None,
+            len => len - 1,
+        };
+        if self.ops[..n] != other.ops[..n] {
+            return None;
+        }
+
+        let mut suffix = Delta::new();
+        let (last, other_op) = (&self.ops[n], &other.ops[n]);
+        if last != other_op {
+            match other_op.split(last.len()) {
+                (Some(left), Some(right)) if &left == last => suffix.add(right),
+                _ => return None,
+            }
+        }
+        other.ops[n + 1..].iter().for_each(|op| suffix.add(op.clone()));
+        Some(suffix)
+    }
+
+    /// Computes the delta that turns `a` into `b` by diffing the characters of them, so the result
+    /// satisfies `Delta::diff(a, b).apply(a) == b`. The identical strings produce a single retain.
+    #[cfg(feature = "std")]
+    pub fn diff(a: &str, b: &str) -> Self {
+        let old = a.chars().collect::<Vec<char>>();
+        let new = b.chars().collect::<Vec<char>>();
+        let utf16_len = |chars: &[char]| chars.iter().map(|c| c.len_utf16()).sum::<usize>();
+        let mut delta = Delta::default();
+        for diff_op in capture_diff_slices(Algorithm::Myers, &old, &new) {
+            let (tag, old_range, new_range) = diff_op.as_tag_tuple();
+            match tag {
+                DiffTag::Equal => delta.retain(utf16_len(&old[old_range]), T::default()),
+                DiffTag::Delete => delta.delete(utf16_len(&old[old_range])),
+                DiffTag::Insert => delta.insert(&new[new_range].iter().collect::<String>(), T::default()),
+                DiffTag::Replace => {
+                    delta.delete(utf16_len(&old[old_range]));
+                    delta.insert(&new[new_range].iter().collect::<String>(), T::default());
+                }
+            }
+        }
+        delta
+    }
+
+    /// Computes the delta that turns `a` into `b` like `diff`, knowing that the caret is at the
+    /// `cursor` of `a`, and returns the cursor transformed by the delta too. The single edit at the
+    /// caret, i.e. typing at the cursor, the backspace or the forward delete, produces exactly that
+    /// insert or delete. The character diff can't tell between the positions of the repeated
+    /// characters, e.g. typing `a` in the middle of `aaa`, so it may put the edit elsewhere and move
+    /// the caret. Any other change falls back to `diff`. The cursor is counted in UTF-16 code
+    /// units.
+    #[cfg(feature = "std")]
+    pub fn diff_with_cursor(a: &str, b: &str, cursor: usize) -> (Self, usize) {
+        let at = match utf16_to_byte_offset(a, cursor) {
+            Some(at) => at,
+            None => {
+                let delta = Self::diff(a, b);
+                let cursor = delta.transform_index(cursor, false);
+                return (delta, cursor);
+            }
+        };
+
+        let (prefix, suffix) = a.split_at(at);
+        let mut delta = Delta::default();
+        if b.len() > a.len() && b.starts_with(prefix) && b.ends_with(suffix) {
+            let inserted = &b[at..b.len() - suffix.len()];
+            delta.retain(count_utf16_code_units(prefix), T::default());
+            delta.insert(inserted, T::default());
+            delta.retain(count_utf16_code_units(suffix), T::default());
+            return (delta, cursor + count_utf16_code_units(inserted));
+        }
+
+        if b.len() < a.len() && b.ends_with(suffix) && a.starts_with(&b[..b.len() - suffix.len()]) {
+            let start = b.len() - suffix.len();
+            delta.retain(count_utf16_code_units(&a[..start]), T::default());
+            delta.delete(count_utf16_code_units(&a[start..at]));
+            delta.retain(count_utf16_code_units(suffix), T::default());
+            return (delta, count_utf16_code_units(&a[..start]));
+        }
+
+        if b.len() < a.len() && b.starts_with(prefix) && a.ends_with(&b[at..]) {
+            let end = a.len() - (b.len() - at);
+            delta.retain(cursor, T::default());
+            delta.delete(count_utf16_code_units(&a[at..end]));
+            delta.retain(count_utf16_code_units(&a[end..]), T::default());
+            return (delta, cursor);
+        }
+
+        let delta = Self::diff(a, b);
+        let cursor = delta.transform_index(cursor, false);
+        (delta, cursor)
+    }
+
+    /// Checks the delta that comes from the outside, e.g. the network. Every operation must be
+    /// non-empty, which also rejects the retain or delete of zero and the insert of the empty
+    /// string, and the cached lengths must equal the lengths computed from the operations.
+    pub fn validate(&self) -> Result<(), OTError> {
+        let mut base_len = 0;
+        let mut target_len = 0;
+        for (index, op) in self.ops.iter().enumerate() {
+            if op.is_empty() {
+                return Err(ErrorBuilder::new(OTErrorCode::InvalidOperation)
+                    .msg(format!("The operation at {} is empty: {}", index, op))
+                    .build());
+            }
+            match op {
+                Operation::Delete(n) => base_len += n,
+                Operation::Retain(retain) => {
+                    base_len += retain.n;
+                    target_len += retain.n;
+                }
+                Operation::Insert(insert) => target_len += insert.utf16_size(),
+            }
+        }
+
+        if base_len != self.utf16_base_len || target_len != self.utf16_target_len {
+            return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength)
+                .msg(format!(
+                    "Expected base len: {}, target len: {}, received: {}, {}",
+                    base_len, target_len, self.utf16_base_len, self.utf16_target_len
+                ))
+                .build());
+        }
+        Ok(())
+    }
+
+    /// Applies the delta to the rope, returning a new rope. Unlike `apply`, which copies the whole
+    /// string, each operation only costs O(log n) plus the size of the edit, so it's meant for the
+    /// large documents. Cloning the rope is cheap because its nodes are shared.
+    #[cfg(feature = "std")]
+    pub fn apply_rope(&self, rope: &Rope) -> Result<Rope, OTError> {
+        self.check_base_len(rope.len_utf16_cu())?;
+        let mut rope = rope.clone();
+        let mut utf16_index = 0;
+        for op in &self.ops {
+            match op {
+                Operation::Retain(retain) => utf16_index += retain.n,
+                Operation::Delete(n) => {
+                    let start = rope.utf16_cu_to_char(utf16_index);
+                    let end = rope.utf16_cu_to_char(utf16_index + n);
+                    rope.remove(start..end);
+                }
+                Operation::Insert(insert) => {
+                    rope.insert(rope.utf16_cu_to_char(utf16_index), &insert.s);
+                    utf16_index += insert.utf16_size();
+                }
+            }
+        }
+        Ok(rope)
+    }
+
     /// Applies an operation to a string, returning a new string.
     pub fn apply(&self, s: &str) -> Result<String, OTError> {
-        let s: FlowyStr = s.into();
-        if s.utf16_size() != self.utf16_base_len {
-            return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength)
+        self.apply_with(s, |_| {})
+    }
+
+    /// Applies the delta to the `document` delta, which consists of the inserts only, and returns
+    /// the new document delta, i.e. `document.compose(self)` for the document-as-delta model. Returns
+    /// the `InvalidOperation` error if the `document` contains any retain or delete, and the
+    /// `IncompatibleLength` error if the delta isn't based on the `document`.
+    pub fn apply_to_delta(&self, document: &Self) -> Result<Self, OTError> {
+        if let Some(index) = document.ops.iter().position(|op| !op.is_insert()) {
+            return Err(ErrorBuilder::new(OTErrorCode::InvalidOperation)
                 .msg(format!(
-                    "Expected: {}, received: {}",
-                    self.utf16_base_len,
-                    s.utf16_size()
+                    "The document contains the non-insert operation at {}: {}",
+                    index, document.ops[index]
                 ))
                 .build());
         }
+        document.try_compose(self)
+    }
+
+    /// Applies the delta to `s` like `apply` if `s` matches the `checksum` of the document the delta
+    /// is based on. Otherwise returns the `ChecksumMismatch` error without applying it.
+    pub fn apply_checked(&self, s: &str, checksum: &BaseChecksum) -> Result<String, OTError> {
+        let base_checksum = BaseChecksum::new(s);
+        if &base_checksum != checksum {
+            return Err(ErrorBuilder::new(OTErrorCode::ChecksumMismatch)
+                .msg(format!("Expected: {:?}, received: {:?}", checksum, base_checksum))
+                .build());
+        }
+        self.apply(s)
+    }
+
+    /// Checks that the delta can be applied to the string of `len` code units. The retains and
+    /// deletes must cover the string exactly, and the operation that runs past the end of the
+    /// string is reported by its index, e.g. the delta built for a longer document.
+    fn check_base_len(&self, len: usize) -> Result<(), OTError> {
+        let mut consumed = 0;
+        for (index, op) in self.ops.iter().enumerate() {
+            if op.is_insert() {
+                continue;
+            }
+            consumed += op.len();
+            if consumed > len {
+                return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength)
+                    .msg(format!(
+                        "The operation at {} runs past the end of the string: {} > {}",
+                        index, consumed, len
+                    ))
+                    .build());
+            }
+        }
+
+        if len != self.utf16_base_len {
+            return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength)
+                .msg(format!("Expected: {}, received: {}", self.utf16_base_len, len))
+                .build());
+        }
+        Ok(())
+    }
+
+    /// Applies the delta to `s` like `apply`, and reports every operation to `f` as it's applied,
+    /// e.g. to let the renderer update the affected nodes only. See `ApplyEvent` for the ranges.
+    pub fn apply_with<'a, F>(&'a self, s: &str, mut f: F) -> Result<String, OTError>
+    where
+        F: FnMut(ApplyEvent<'a, T>),
+    {
+        let s: FlowyStr = s.into();
+        self.check_base_len(s.utf16_size())?;
         let mut new_s = String::new();
+        let mut offset = 0;
         let code_point_iter = &mut s.utf16_code_unit_iter();
         for op in &self.ops {
             match &op {
                 Operation::Retain(retain) => {
-                    for c in code_point_iter.take(retain.n as usize) {
-                        new_s.push_str(str::from_utf8(c.0).unwrap_or(""));
-                    }
+                    consume_utf16_code_units(code_point_iter, retain.n, Some(&mut new_s));
+                    f(ApplyEvent::Retain {
+                        interval: Interval::new(offset, offset + retain.n),
+                        attributes: &retain.attributes,
+                    });
+                    offset += retain.n;
                 }
                 Operation::Delete(delete) => {
-                    for _ in 0..*delete {
-                        code_point_iter.next();
-                    }
+                    consume_utf16_code_units(code_point_iter, *delete, None);
+                    f(ApplyEvent::Delete {
+                        index: offset,
+                        n: *delete,
+                    });
                 }
                 Operation::Insert(insert) => {
                     new_s += &insert.s;
+                    f(ApplyEvent::Insert {
+                        interval: Interval::new(offset, offset + insert.utf16_size()),
+                        s: &insert.s,
+                        attributes: &insert.attributes,
+                    });
+                    offset += insert.utf16_size();
                 }
             }
         }
@@ -182,44 +777,546 @@ where
     }
 
     /// Computes the inverse of an operation. The inverse of an operation is the
-    /// operation that reverts the effects of the operation
+    /// operation that reverts the effects of the operation.
+    ///
+    /// The plain string doesn't carry any attributes, so the retains that change the attributes
+    /// can't be restored here. Use `invert` with the document delta to undo the formatting.
     pub fn invert_str(&self, s: &str) -> Self {
         let mut inverted = Delta::default();
-        let chars = &mut s.chars();
+        let s: FlowyStr = s.into();
+        let code_point_iter = &mut s.utf16_code_unit_iter();
         for op in &self.ops {
             match &op {
                 Operation::Retain(retain) => {
                     inverted.retain(retain.n, T::default());
-                    // TODO: use advance_by instead, but it's unstable now
-                    // chars.advance_by(retain.num)
-                    for _ in 0..retain.n {
-                        chars.next();
-                    }
+                    consume_utf16_code_units(code_point_iter, retain.n, None);
                 }
                 Operation::Insert(insert) => {
                     inverted.delete(insert.utf16_size());
                 }
                 Operation::Delete(delete) => {
-                    inverted.insert(&chars.take(*delete as usize).collect::<String>(), op.get_attributes());
+                    let mut deleted = String::new();
+                    consume_utf16_code_units(code_point_iter, *delete, Some(&mut deleted));
+                    inverted.insert(&deleted, op.get_attributes());
                 }
             }
         }
         inverted
     }
 
-    /// Checks if this operation has no effect.
+    /// Returns the text of `s` that is removed by the deletes of the delta, concatenated in the
+    /// document order, e.g. the text that is cut to the clipboard. The length of `s` must match
+    /// the base length of the delta.
+    pub fn removed_text(&self, s: &str) -> Result<String, OTError> {
+        let s: FlowyStr = s.into();
+        self.check_base_len(s.utf16_size())?;
+        let mut removed = String::new();
+        let code_point_iter = &mut s.utf16_code_unit_iter();
+        for op in &self.ops {
+            match op {
+                Operation::Retain(retain) => {
+                    consume_utf16_code_units(code_point_iter, retain.n, None);
+                }
+                Operation::Delete(n) => {
+                    consume_utf16_code_units(code_point_iter, *n, Some(&mut removed));
+                }
+                Operation::Insert(_) => {}
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Returns true if applying the delta changes nothing, i.e. it's empty or consists of the
+    /// plain retains only. The retain with attributes formats the text, so it's not a noop.
     #[inline]
     pub fn is_noop(&self) -> bool {
-        matches!(self.ops.as_slice(), [] | [Operation::Retain(_)])
+        self.ops.iter().all(|op| op.is_noop())
     }
 
     pub fn is_empty(&self) -> bool {
         self.ops.is_empty()
     }
 
+    /// Returns the operations within [start, end). Like Quill, the range is counted over the
+    /// lengths of the operations, and the operations at the boundaries are cut with their
+    /// attributes kept. An empty or reversed range returns an empty delta.
+    pub fn slice(&self, start: usize, end: usize) -> Self {
+        if start >= end {
+            return Delta::default();
+        }
+        DeltaIter::from_interval(self, Interval::new(start, end)).collect()
+    }
+
+    /// Splits off the operations that produce the first `n` characters of the output and returns
+    /// them, leaving the rest in the delta, e.g. to render the first page of the document delta.
+    /// Unlike `slice`, the `n` is counted over the output, i.e. the inserts and retains, and the
+    /// deletes before the cut go to the prefix. The operation at the cut is split with its
+    /// attributes kept on both halves. The `n` is counted in UTF-16 code units.
+    pub fn take_prefix(&mut self, n: usize) -> Self {
+        let mut prefix = Delta::new();
+        let mut rest = Delta::new();
+        let mut remaining = n;
+        for op in core::mem::take(&mut self.ops) {
+            if remaining == 0 {
+                rest.add(op);
+                continue;
+            }
+
+            match &op {
+                Operation::Delete(_) => prefix.add(op),
+                _ => {
+                    let (left, right) = op.split(remaining);
+                    if let Some(left) = left {
+                        remaining -= left.len();
+                        prefix.add(left);
+                    }
+                    if let Some(right) = right {
+                        rest.add(right);
+                    }
+                }
+            }
+        }
+        *self = rest;
+        prefix
+    }
+
+    /// Walks the characters of the inserts along with the attributes of the insert each character
+    /// belongs to, or `None` for the plain text. The retains and deletes are skipped, so it's
+    /// meant for the document delta.
+    pub fn iter_chars(&self) -> impl Iterator<Item = (char, Option<T>)> + '_ {
+        self.ops
+            .iter()
+            .filter_map(|op| match op {
+                Operation::Insert(insert) => Some(insert),
+                _ => None,
+            })
+            .flat_map(|insert| {
+                insert
+                    .s
+                    .chars()
+                    .map(move |c| (c, (!insert.attributes.is_empty()).then(|| insert.attributes.clone())))
+            })
+    }
+
+    /// Returns the attributes in effect at the cursor `index` of the document delta, i.e. the ones
+    /// that the text typed there takes. The `index` is counted in UTF-16 code units. At the boundary
+    /// of two inserts, the attributes of the preceding insert are returned, just like the editor
+    /// continues the formatting of the text before the cursor. The index past the end of the
+    /// document returns the empty attributes.
+    pub fn attributes_at(&self, index: usize) -> T {
+        let mut offset = 0;
+        for op in &self.ops {
+            if let Operation::Insert(insert) = op {
+                offset += insert.utf16_size();
+                if index <= offset {
+                    return insert.attributes.clone();
+                }
+            }
+        }
+        T::default()
+    }
+
+    /// Concatenates the strings of the inserts without any formatting, e.g. for the search index
+    /// or copying the plain text. Unlike `to_str`, the retains and deletes are skipped instead of
+    /// failing. Every embed becomes one `EMBED_PLACEHOLDER`.
+    pub fn plain_text(&self) -> String {
+        self.ops
+            .iter()
+            .filter_map(|op| match op {
+                Operation::Insert(insert) => Some(insert.s.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the length of the text inserted by the delta, counted in UTF-16 code units like
+    /// `target_len`. Together with `char_len_of_deletes`, it tells how much the delta changes the
+    /// document: `base_len + char_len_of_inserts - char_len_of_deletes == target_len`.
+    pub fn char_len_of_inserts(&self) -> usize {
+        self.ops.iter().filter(|op| op.is_insert()).map(|op| op.len()).sum()
+    }
+
+    /// Returns the length of the text deleted by the delta, counted in UTF-16 code units like
+    /// `base_len`.
+    pub fn char_len_of_deletes(&self) -> usize {
+        self.ops.iter().filter(|op| op.is_delete()).map(|op| op.len()).sum()
+    }
+
+    /// Composes `other` into self, the result is the same as `*self = self.compose(other)?`.
+    ///
+    /// The operations of self before the leading plain retain of `other` aren't changed by it, so
+    /// they are kept in place and only the rest is composed. It saves the allocations when
+    /// folding the incoming deltas into the document, which are usually small edits.
+    pub fn compose_in_place(&mut self, other: &Self) -> Result<(), OTError> {
+        let start = match other.ops.first() {
+            Some(op) if op.is_noop() => op.len(),
+            _ => 0,
+        };
+
+        // Finds the operations that end before the start. The deletes at the start are left to
+        // the rest, so the inserts of other are placed before them just like compose does.
+        let (mut index, mut base_len, mut target_len) = (0, 0, 0);
+        while index < self.ops.len() && target_len < start {
+            let op = &self.ops[index];
+            let op_target_len = if op.is_delete() { 0 } else { op.len() };
+            if target_len + op_target_len > start {
+                match op.split(start - target_len) {
+                    (Some(left), Some(right)) => {
+                        self.ops[index] = left;
+                        self.ops.insert(index + 1, right);
+                        continue;
+                    }
+                    _ => break,
+                }
+            }
+            if !op.is_insert() {
+                base_len += op.len();
+            }
+            target_len += op_target_len;
+            index += 1;
+        }
+
+        let rest_ops = self.ops.split_off(index);
+        let rest = Delta {
+            utf16_base_len: self.utf16_base_len - base_len,
+            utf16_target_len: self.utf16_target_len - target_len,
+            ops: rest_ops,
+        };
+        // The leading retain of other may go beyond the end of self, the retain that is left is
+        // kept for the rest.
+        let mut other_rest = Delta::with_capacity(other.ops.len());
+        other_rest.retain(start - target_len, T::default());
+        let skip = if start > 0 { 1 } else { 0 };
+        other.ops.iter().skip(skip).for_each(|op| other_rest.add(op.clone()));
+        let composed = match rest.compose(&other_rest) {
+            Ok(composed) => composed,
+            Err(e) => {
+                // Puts the rest back, the split operations are merged again by the normalize.
+                self.ops.extend(rest.ops);
+                self.normalize();
+                return Err(e);
+            }
+        };
+
+        self.utf16_base_len = base_len;
+        self.utf16_target_len = target_len;
+        let mut ops = composed.ops.into_iter();
+        if let Some(op) = ops.next() {
+            self.add(op);
+        }
+        self.ops.extend(ops);
+        self.utf16_base_len = base_len + composed.utf16_base_len;
+        self.utf16_target_len = target_len + composed.utf16_target_len;
+        Ok(())
+    }
+
+    /// Composes `other` like `compose`, but returns the `IncompatibleLength` error unless the base
+    /// length of `other` is the target length of the delta. `compose` doesn't check it, use this
+    /// one for the deltas that must line up exactly, e.g. the revisions received from the server.
+    pub fn try_compose(&self, other: &Self) -> Result<Self, OTError> {
+        if self.utf16_target_len != other.utf16_base_len {
+            return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength)
+                .msg(format!(
+                    "The target length {} doesn't match the base length {} of the other",
+                    self.utf16_target_len, other.utf16_base_len
+                ))
+                .build());
+        }
+        self.compose(other)
+    }
+
+    /// Composes the sequential deltas from left to right into one delta, e.g. to replay the edit
+    /// history. The empty slice produces an empty delta.
+    pub fn compose_many(deltas: &[Self]) -> Result<Self, OTError> {
+        let mut iter = deltas.iter();
+        match iter.next() {
+            None => Ok(Delta::default()),
+            Some(first) => iter.try_fold(first.clone(), |composed, delta| composed.compose(delta)),
+        }
+    }
+
+    /// Transforms `other` against the delta, both of them are based on the same document, and
+    /// returns `other'` that achieves the intention of `other` after the delta is applied, i.e.
+    /// `a.compose(a.transform_with_priority(b, true)) == b.compose(b.transform_with_priority(a, false))`.
+    ///
+    /// If `priority` is true, the delta is considered to happen first, so its inserts are placed
+    /// before the inserts of `other` at the same position and its attributes win on the
+    /// overlapping retains.
+    pub fn transform_with_priority(&self, other: &Self, priority: bool) -> Result<Self, OTError> {
+        if priority {
+            let (_, other_prime) = self.transform(other)?;
+            Ok(other_prime)
+        } else {
+            let (other_prime, _) = other.transform(self)?;
+            Ok(other_prime)
+        }
+    }
+
+    /// Transforms `other` against the delta like `transform_with_priority`, the `tie_break` decides
+    /// which of them goes first. The `ById` takes the site id of the delta and the one of `other`.
+    pub fn transform_with_tie_break(&self, other: &Self, tie_break: TieBreak) -> Result<Self, OTError> {
+        self.transform_with_priority(other, tie_break.priority())
+    }
+
+    /// Rebases the local delta onto the remote delta `onto`, which is based on the same document and
+    /// has already been applied, e.g. by the server. The returned delta applies on top of `onto` and
+    /// keeps the intention of the local delta, i.e. `onto.compose(&local.rebase(&onto)?)` is the
+    /// document with both edits.
+    ///
+    /// It's `onto.transform_with_priority(self, true)`: the remote delta goes first, so at the same
+    /// position the remote inserts stay before the local ones (unless the gravity of the inserts says
+    /// otherwise), and the remote attributes win where both of them format the same text.
+    pub fn rebase(&self, onto: &Self) -> Result<Self, OTError> {
+        onto.transform_with_priority(self, true)
+    }
+
+    /// Splits the document delta at every newline and calls `f` with the delta of each line and the
+    /// block attributes of the newline that ends it, see `Attributes::split_block`. The iteration
+    /// stops if `f` returns false.
+    ///
+    /// The content after the last newline is always passed as the last line with the default
+    /// attributes, so the trailing newline produces an empty last line and the document without
+    /// any newline produces one line. Like Quill, it stops at the first operation that isn't an
+    /// insert.
+    pub fn each_line<F>(&self, mut f: F)
+    where
+        F: FnMut(Delta<T>, &T) -> bool,
+    {
+        let mut line = Delta::new();
+        for op in &self.ops {
+            let insert = match op {
+                Operation::Insert(insert) => insert,
+                _ => return,
+            };
+
+            if insert.embed.is_some() {
+                line.add(op.clone());
+                continue;
+            }
+
+            for (i, text) in insert.s.split(NEW_LINE).enumerate() {
+                if i > 0 && !f(core::mem::take(&mut line), &insert.attributes.split_block().0) {
+                    return;
+                }
+                line.insert(text, insert.attributes.clone());
+            }
+        }
+        f(line, &T::default());
+    }
+
+    /// Returns the number of lines of the document delta, which is the number of newlines in
+    /// the inserts plus one. It's the same as the number of lines visited by `each_line`, so the
+    /// empty document has one line and the trailing newline starts an empty last line.
+    pub fn lines(&self) -> usize {
+        let newlines: usize = self
+            .ops
+            .iter()
+            .map(|op| match op {
+                Operation::Insert(insert) => bytecount::count(insert.s.as_bytes(), b'\n'),
+                _ => 0,
+            })
+            .sum();
+        newlines + 1
+    }
+
+    /// Splits the document delta before the line of `line_index`, e.g. to move or delete the
+    /// paragraphs. The first half has the lines before it, each with the newline that ends it, and
+    /// the second half has the rest. The `line_index` zero puts the whole document in the second
+    /// half, and the `line_index` beyond the last line, see `lines`, puts it in the first half.
+    pub fn split_at_newline(&self, line_index: usize) -> (Self, Self) {
+        let mut offset = 0;
+        let mut newlines = 0;
+        if line_index > 0 {
+            let chars = self
+                .ops
+                .iter()
+                .filter_map(|op| match op {
+                    Operation::Insert(insert) => Some(insert),
+                    _ => None,
+                })
+                .flat_map(|insert| insert.s.chars());
+            for c in chars {
+                offset += c.len_utf16();
+                if c == '\n' {
+                    newlines += 1;
+                    if newlines == line_index {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if line_index > newlines {
+            return (self.clone(), Delta::default());
+        }
+        let mut rest = self.clone();
+        let first = rest.take_prefix(offset);
+        (first, rest)
+    }
+
+    /// Transforms the index, e.g. the cursor position, against the delta so that it stays at the
+    /// same logical position after applying the delta. The inserts before the index push it to
+    /// the right and the deletes before it pull it to the left. Like all the lengths of the delta,
+    /// the index is counted in UTF-16 code units, which is the same as the string offsets in
+    /// JavaScript, so the cursor position can be exchanged with the frontend directly.
+    ///
+    /// If `priority` is true, the insert at exactly the index won't move the index.
+    pub fn transform_index(&self, index: usize, priority: bool) -> usize {
+        let mut index = index;
+        let mut offset = 0;
+        for op in &self.ops {
+            if offset > index {
+                break;
+            }
+
+            let len = op.len();
+            match op {
+                Operation::Delete(_) => {
+                    index -= min(len, index - offset);
+                    continue;
+                }
+                Operation::Insert(_) => {
+                    if offset < index || !priority {
+                        index += len;
+                    }
+                }
+                Operation::Retain(_) => {}
+            }
+            offset += len;
+        }
+        index
+    }
+
+    /// Transforms the selection [start, end) against the delta like `transform_index` does for both
+    /// ends. The inserts within the selection grow it, and the inserts at its ends are included if
+    /// `grow` is true, otherwise they are placed outside of it. The selection collapses to a caret if
+    /// all the selected text is deleted.
+    pub fn transform_selection(&self, selection: Interval, grow: bool) -> Interval {
+        let start = self.transform_index(selection.start, grow);
+        let end = self.transform_index(selection.end, !grow);
+        Interval::new(start, max(start, end))
+    }
+
+    /// Removes the trailing retain operation with empty attributes, if present. The retain that
+    /// carries attributes is kept because it changes the format of the document.
+    /// Both lengths are reduced by the removed retain, so the chopped delta is based on the part
+    /// of the document it actually covers.
+    pub fn chop(&mut self) {
+        self.trim_end();
+    }
+
+    /// Returns true if the deltas are equal after chopping their trailing plain retains, see `chop`,
+    /// e.g. `insert("a")` and `insert("a").retain(5)`. Unlike `==`, the lengths aren't compared
+    /// either, because chopping reduces them.
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        chopped_ops(self) == chopped_ops(other)
+    }
+
+    /// Removes the trailing plain retain, see `chop`.
+    pub fn trim_end(&mut self) {
+        if let Some(last) = self.ops.last() {
+            if last.is_noop() {
+                let n = last.len();
+                self.ops.pop();
+                self.utf16_base_len -= n;
+                self.utf16_target_len -= n;
+            }
+        }
+    }
+
+    /// Removes the leading retain operation with empty attributes, if present, and returns its
+    /// length. The retain that carries attributes is kept.
+    ///
+    /// The lengths are reduced by the removed retain like `trim_end` does. The delta then starts at
+    /// the offset of the returned length, e.g. the delta sliced from the middle of the document
+    /// starts at the slice.
+    pub fn trim_start(&mut self) -> usize {
+        match self.ops.first() {
+            Some(first) if first.is_noop() => {
+                let n = self.ops.remove(0).len();
+                self.utf16_base_len -= n;
+                self.utf16_target_len -= n;
+                n
+            }
+            _ => 0,
+        }
+    }
+
     pub fn extend(&mut self, other: Self) {
         other.ops.into_iter().for_each(|op| self.add(op));
     }
+
+    /// Composes the `other` delta that changes the format only, i.e. it consists of the retains.
+    /// It's the fast path of `compose` for the common formatting edits: the operations covered
+    /// by the plain retains are moved to the result as they are, and only the ones covered by the
+    /// retains with attributes are split and formatted. The result is the same as `compose`.
+    ///
+    /// Returns the `ComposeOperationFail` error if `other` contains any insert or delete.
+    pub fn compose_attributes_only(&self, other: &Self) -> Result<Self, OTError> {
+        if let Some(op) = other.ops.iter().find(|op| !op.is_retain()) {
+            return Err(ErrorBuilder::new(OTErrorCode::ComposeOperationFail)
+                .msg(format!("The operation {} changes the content", op))
+                .build());
+        }
+
+        let mut composed = Delta::with_capacity(self.ops.len());
+        let mut ops = self.ops.iter().cloned();
+        let mut rest: Option<Operation<T>> = None;
+        for other_op in &other.ops {
+            let attributes = other_op.get_attributes();
+            let mut n = other_op.len();
+            while n > 0 {
+                let op = match rest.take().or_else(|| ops.next()) {
+                    Some(op) => op,
+                    None => {
+                        // The retain beyond the end of the delta formats the retained document.
+                        composed.retain(n, attributes.clone());
+                        break;
+                    }
+                };
+                if op.is_delete() {
+                    composed.add(op);
+                    continue;
+                }
+
+                let (op, op_rest) = match op.len() > n {
+                    true => op.split(n),
+                    false => (Some(op), None),
+                };
+                rest = op_rest;
+                let mut op = match op {
+                    Some(op) => op,
+                    None => continue,
+                };
+                n -= op.len();
+                if !attributes.is_empty() {
+                    let mut composed_attrs = op.get_attributes().compose(&attributes)?;
+                    if op.is_insert() {
+                        composed_attrs.remove_empty();
+                    }
+                    op.set_attributes(composed_attrs);
+                }
+                composed.add(op);
+            }
+        }
+
+        rest.into_iter().chain(ops).for_each(|op| composed.add(op));
+        Ok(composed)
+    }
+
+    /// Appends a plain retain that brings the base length of the delta up to `base_len`, so the
+    /// delta that only touches the beginning of the document covers the whole document. Nothing
+    /// is appended if the delta already covers `base_len`. The delta must not consume more than
+    /// `base_len`, which is checked in debug builds.
+    pub fn retain_to_end(&mut self, base_len: usize) {
+        debug_assert!(
+            self.utf16_base_len <= base_len,
+            "The delta consumes {} code units, more than the base length {}",
+            self.utf16_base_len,
+            base_len
+        );
+        self.retain(base_len.saturating_sub(self.utf16_base_len), T::default());
+    }
 }
 
 impl<T> OperationTransformable for Delta<T>
@@ -230,6 +1327,10 @@ where
     where
         Self: Sized,
     {
+        if other.ops.iter().all(|op| op.is_retain()) {
+            return self.compose_attributes_only(other);
+        }
+
         let mut new_delta = Delta::default();
         let mut iter = DeltaIter::new(self);
         let mut other_iter = DeltaIter::new(other);
@@ -268,7 +1369,9 @@ where
                 (Operation::Insert(insert), Operation::Retain(other_retain)) => {
                     let mut composed_attrs = insert.attributes.compose(&other_retain.attributes)?;
                     composed_attrs.remove_empty();
-                    new_delta.add(OpBuilder::insert(op.get_data()).attributes(composed_attrs).build())
+                    let mut insert = insert.clone();
+                    insert.attributes = composed_attrs;
+                    new_delta.add(Operation::Insert(insert))
                 }
                 (Operation::Retain(_), Operation::Delete(_)) => {
                     new_delta.add(other_op);
@@ -307,16 +1410,23 @@ where
         loop {
             match (&next_op1, &next_op2) {
                 (None, None) => break,
-                (Some(Operation::Insert(insert)), _) => {
-                    // let composed_attrs = transform_attributes(&next_op1, &next_op2, true);
-                    a_prime.insert(&insert.s, insert.attributes.clone());
-                    b_prime.retain(insert.utf16_size(), insert.attributes.clone());
-                    next_op1 = ops1.next();
-                }
+                (Some(Operation::Insert(insert)), _) => match &next_op2 {
+                    // The gravities of the concurrent inserts decide which one goes first.
+                    Some(Operation::Insert(o_insert)) if is_insert_after(insert, o_insert) => {
+                        a_prime.retain(o_insert.utf16_size(), T::default());
+                        b_prime.add(Operation::Insert(o_insert.clone()));
+                        next_op2 = ops2.next();
+                    }
+                    _ => {
+                        // let composed_attrs = transform_attributes(&next_op1, &next_op2, true);
+                        a_prime.add(Operation::Insert(insert.clone()));
+                        b_prime.retain(insert.utf16_size(), insert.attributes.clone());
+                        next_op1 = ops1.next();
+                    }
+                },
                 (_, Some(Operation::Insert(o_insert))) => {
-                    let composed_attrs = transform_op_attribute(&next_op1, &next_op2)?;
-                    a_prime.retain(o_insert.utf16_size(), composed_attrs.clone());
-                    b_prime.insert(&o_insert.s, composed_attrs);
+                    a_prime.retain(o_insert.utf16_size(), T::default());
+                    b_prime.add(Operation::Insert(o_insert.clone()));
                     next_op2 = ops2.next();
                 }
                 (None, _) => {
@@ -326,24 +1436,32 @@ where
                     return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength).build());
                 }
                 (Some(Operation::Retain(retain)), Some(Operation::Retain(o_retain))) => {
-                    let composed_attrs = transform_op_attribute(&next_op1, &next_op2)?;
+                    // The attributes of self win when both of them format the same range.
+                    let a_prime_attrs = o_retain.attributes.transform_with_priority(&retain.attributes, false);
+                    let b_prime_attrs = retain.attributes.transform_with_priority(&o_retain.attributes, true);
                     match retain.cmp(o_retain) {
                         Ordering::Less => {
-                            a_prime.retain(retain.n, composed_attrs.clone());
-                            b_prime.retain(retain.n, composed_attrs.clone());
-                            next_op2 = Some(OpBuilder::retain(o_retain.n - retain.n).build());
+                            a_prime.retain(retain.n, a_prime_attrs);
+                            b_prime.retain(retain.n, b_prime_attrs);
+                            next_op2 = Some(
+                                OpBuilder::retain_with_attributes(o_retain.n - retain.n, o_retain.attributes.clone())
+                                    .build(),
+                            );
                             next_op1 = ops1.next();
                         }
                         Ordering::Equal => {
-                            a_prime.retain(retain.n, composed_attrs.clone());
-                            b_prime.retain(retain.n, composed_attrs.clone());
+                            a_prime.retain(retain.n, a_prime_attrs);
+                            b_prime.retain(retain.n, b_prime_attrs);
                             next_op1 = ops1.next();
                             next_op2 = ops2.next();
                         }
                         Ordering::Greater => {
-                            a_prime.retain(o_retain.n, composed_attrs.clone());
-                            b_prime.retain(o_retain.n, composed_attrs.clone());
-                            next_op1 = Some(OpBuilder::retain(retain.n - o_retain.n).build());
+                            a_prime.retain(o_retain.n, a_prime_attrs);
+                            b_prime.retain(o_retain.n, b_prime_attrs);
+                            next_op1 = Some(
+                                OpBuilder::retain_with_attributes(retain.n - o_retain.n, retain.attributes.clone())
+                                    .build(),
+                            );
                             next_op2 = ops2.next();
                         }
                     };
@@ -366,7 +1484,9 @@ where
                     match i.cmp(o_retain) {
                         Ordering::Less => {
                             a_prime.delete(*i);
-                            next_op2 = Some(OpBuilder::retain(o_retain.n - *i).build());
+                            next_op2 = Some(
+                                OpBuilder::retain_with_attributes(o_retain.n - *i, o_retain.attributes.clone()).build(),
+                            );
                             next_op1 = ops1.next();
                         }
                         Ordering::Equal => {
@@ -395,7 +1515,9 @@ where
                         }
                         Ordering::Greater => {
                             b_prime.delete(*j);
-                            next_op1 = Some(OpBuilder::retain(retain.n - *j).build());
+                            next_op1 = Some(
+                                OpBuilder::retain_with_attributes(retain.n - *j, retain.attributes.clone()).build(),
+                            );
                             next_op2 = ops2.next();
                         }
                     };
@@ -440,16 +1562,76 @@ where
     }
 }
 
+fn chopped_ops<T: Attributes>(delta: &Delta<T>) -> &[Operation<T>] {
+    match delta.ops.split_last() {
+        Some((last, ops)) if last.is_noop() => ops,
+        _ => &delta.ops,
+    }
+}
+
+// Returns `None` if the `utf16_index` is beyond the end or in the middle of a surrogate pair.
+#[cfg(feature = "std")]
+fn utf16_to_byte_offset(s: &str, utf16_index: usize) -> Option<usize> {
+    let mut utf16_len = 0;
+    for (offset, c) in s.char_indices() {
+        if utf16_len == utf16_index {
+            return Some(offset);
+        }
+        utf16_len += c.len_utf16();
+        if utf16_len > utf16_index {
+            return None;
+        }
+    }
+    if utf16_len == utf16_index {
+        Some(s.len())
+    } else {
+        None
+    }
+}
+
+fn add_sorted_run<T: Attributes>(delta: &mut Delta<T>, run: &mut Vec<Operation<T>>) {
+    run.sort_by(|a, b| a.canonical_cmp(b));
+    run.drain(..).for_each(|op| delta.add(op));
+}
+
+// Returns true if the `insert` must be placed after the concurrent `other` insert at the same
+// position, which depends on their gravities only. Otherwise the insert of self goes first.
+fn is_insert_after<T: Attributes>(insert: &Insert<T>, other: &Insert<T>) -> bool {
+    fn rank(gravity: Option<Gravity>) -> u8 {
+        match gravity {
+            Some(Gravity::Left) => 0,
+            None => 1,
+            Some(Gravity::Right) => 2,
+        }
+    }
+    rank(insert.attributes.gravity()) > rank(other.attributes.gravity())
+}
+
 /// Removes trailing retain operation with empty attributes, if present.
 pub fn trim<T>(delta: &mut Delta<T>)
 where
     T: Attributes,
 {
-    if let Some(last) = delta.ops.last() {
-        if last.is_retain() && last.is_plain() {
-            delta.ops.pop();
+    delta.chop();
+}
+
+/// Advances the iterator by `n` UTF-16 code units and appends the consumed characters to `buf`
+/// if it's not None. Returns the number of code units that were consumed.
+fn consume_utf16_code_units(iter: &mut Utf16CodeUnitIterator, n: usize, mut buf: Option<&mut String>) -> usize {
+    let mut consumed = 0;
+    while consumed < n {
+        match iter.next() {
+            None => break,
+            Some((bytes, len)) => {
+                // The characters that take four bytes in UTF-8 take two code units in UTF-16.
+                consumed += if len == 4 { 2 } else { 1 };
+                if let Some(buf) = buf.as_mut() {
+                    buf.push_str(str::from_utf8(bytes).unwrap_or(""));
+                }
+            }
         }
     }
+    consumed
 }
 
 fn invert_from_other<T: Attributes>(
@@ -466,13 +1648,13 @@ fn invert_from_other<T: Attributes>(
             // tracing::trace!("invert delete: {} by add {}", n, other_op);
             base.add(other_op);
         }
-        Operation::Retain(_retain) => {
+        Operation::Retain(retain) => {
             tracing::trace!(
                 "invert attributes: {:?}, {:?}",
-                operation.get_attributes(),
-                other_op.get_attributes()
+                retain.attributes,
+                other_op.attributes_ref()
             );
-            let inverted_attrs = operation.get_attributes().invert(&other_op.get_attributes());
+            let inverted_attrs = operation.invert_attributes(&other_op.get_attributes());
             base.retain(other_op.len(), inverted_attrs);
         }
         Operation::Insert(_) => {
@@ -481,33 +1663,23 @@ fn invert_from_other<T: Attributes>(
     });
 }
 
-fn transform_op_attribute<T: Attributes>(
-    left: &Option<Operation<T>>,
-    right: &Option<Operation<T>>,
-) -> Result<T, OTError> {
-    if left.is_none() {
-        if right.is_none() {
-            return Ok(T::default());
-        }
-        return Ok(right.as_ref().unwrap().get_attributes());
-    }
-    let left = left.as_ref().unwrap().get_attributes();
-    let right = right.as_ref().unwrap().get_attributes();
-    // TODO: replace with anyhow and thiserror.
-    Ok(left.transform(&right)?.0)
-}
-
 impl<T> Delta<T>
 where
     T: Attributes + DeserializeOwned,
 {
+    /// Deserializes the delta from the JSON array of the operations or the Quill delta object. If
+    /// the JSON is malformed, the `SerdeError` error tells the position where the parsing failed
+    /// and, if the JSON itself is valid, the index of the first malformed operation.
     pub fn from_delta_str(json: &str) -> Result<Self, OTError> {
-        let delta = serde_json::from_str(json).map_err(|e| {
+        serde_json::from_str(json).map_err(|e| {
             tracing::trace!("Deserialize failed: {:?}", e);
             tracing::trace!("{:?}", json);
-            e
-        })?;
-        Ok(delta)
+            let msg = match malformed_operation_index::<T>(json) {
+                Some(index) => format!("Invalid operation at index {}: {}", index, e),
+                None => e.to_string(),
+            };
+            ErrorBuilder::new(OTErrorCode::SerdeError).msg(msg).build()
+        })
     }
 
     pub fn from_bytes<B: AsRef<[u8]>>(bytes: B) -> Result<Self, OTError> {
@@ -515,6 +1687,34 @@ where
         let val = Self::from_delta_str(&json)?;
         Ok(val)
     }
+
+    /// Deserializes the delta from the JSON read from the `reader`, e.g. the large delta stored in
+    /// a file. Every operation is added to the delta as soon as it's parsed, which keeps the
+    /// lengths up to date, so neither the JSON text nor the array of the operations is held in
+    /// memory. The `reader` isn't buffered here, wrap it with the `BufReader` if needed.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: std::io::Read>(reader: R) -> Result<Self, OTError> {
+        let delta = serde_json::from_reader(reader)?;
+        Ok(delta)
+    }
+}
+
+// Parses the operations one by one to find the malformed one, it's only called after the delta
+// failed to parse.
+fn malformed_operation_index<T>(json: &str) -> Option<usize>
+where
+    T: Attributes + DeserializeOwned,
+{
+    let ops = match serde_json::from_str(json).ok()? {
+        serde_json::Value::Array(ops) => ops,
+        serde_json::Value::Object(mut map) => match map.remove("ops") {
+            Some(serde_json::Value::Array(ops)) => ops,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    ops.into_iter()
+        .position(|op| serde_json::from_value::<Operation<T>>(op).is_err())
 }
 
 impl<T> Delta<T>
@@ -529,6 +1729,12 @@ where
         self.apply("")
     }
 
+    /// Serializes the delta to the shape of Quill, the operations are wrapped in an object
+    /// with the `ops` key. The `to_delta_str` keeps the plain array for the stored revisions.
+    pub fn to_quill_delta_str(&self) -> String {
+        format!(r#"{{"ops":{}}}"#, self.to_delta_str())
+    }
+
     pub fn to_delta_bytes(&self) -> Bytes {
         let json = self.to_delta_str();
         Bytes::from(json.into_bytes())
@@ -548,7 +1754,27 @@ where
     }
 }
 
-impl<T> std::convert::TryFrom<Vec<u8>> for Delta<T>
+impl<T> core::convert::From<&str> for Delta<T>
+where
+    T: Attributes,
+{
+    fn from(s: &str) -> Self {
+        let mut delta = Delta::with_capacity(1);
+        delta.add(Operation::Insert(s.into()));
+        delta
+    }
+}
+
+impl<T> core::convert::From<String> for Delta<T>
+where
+    T: Attributes,
+{
+    fn from(s: String) -> Self {
+        Delta::from(s.as_str())
+    }
+}
+
+impl<T> core::convert::TryFrom<Vec<u8>> for Delta<T>
 where
     T: Attributes + DeserializeOwned,
 {
@@ -558,7 +1784,7 @@ where
     }
 }
 
-impl<T> std::convert::TryFrom<Bytes> for Delta<T>
+impl<T> core::convert::TryFrom<Bytes> for Delta<T>
 where
     T: Attributes + DeserializeOwned,
 {
@@ -568,3 +1794,28 @@ where
         Delta::from_bytes(&bytes)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{OperationTransformable, PlainTextAttributes, PlainTextDelta};
+
+    // Runs without the std feature too, the compose and the apply only need alloc.
+    #[test]
+    fn delta_compose_and_apply_with_alloc_only() {
+        let mut a = PlainTextDelta::new();
+        a.insert("123", PlainTextAttributes::default());
+        let mut b = PlainTextDelta::new();
+        b.retain(1, PlainTextAttributes::default());
+        b.delete(1);
+        b.insert("456", PlainTextAttributes::default());
+        b.retain(1, PlainTextAttributes::default());
+
+        let composed = a.compose(&b).unwrap();
+        assert_eq!(composed.apply("").unwrap(), "14563");
+        assert_eq!(b.apply("123").unwrap(), "14563");
+        assert_eq!(composed.to_delta_str(), r#"[{"insert":"14563"}]"#);
+
+        let inverted = b.invert_str("123");
+        assert_eq!(inverted.apply("14563").unwrap(), "123");
+    }
+}
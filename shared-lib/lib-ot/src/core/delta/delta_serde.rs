@@ -1,10 +1,11 @@
 use crate::core::{Attributes, Delta};
+use alloc::string::String;
+use core::{fmt, marker::PhantomData};
 use serde::{
-    de::{SeqAccess, Visitor},
+    de::{IgnoredAny, MapAccess, SeqAccess, Visitor},
     ser::SerializeSeq,
     Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::{fmt, marker::PhantomData};
 
 impl<T> Serialize for Delta<T>
 where
@@ -39,7 +40,7 @@ where
             type Value = Delta<T>;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("a sequence")
+                formatter.write_str("a sequence or a map with ops")
             }
 
             #[inline]
@@ -53,8 +54,27 @@ where
                 }
                 Ok(o)
             }
+
+            // Quill wraps the operations in an object, e.g. {"ops": [{"insert": "123"}]}
+            #[inline]
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut o = Delta::default();
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "ops" {
+                        // Deserializes the ops as the delta, so they are added one by one too.
+                        let ops: Delta<T> = map.next_value()?;
+                        o.extend(ops);
+                    } else {
+                        let _ = map.next_value::<IgnoredAny>()?;
+                    }
+                }
+                Ok(o)
+            }
         }
 
-        deserializer.deserialize_seq(OperationSeqVisitor(PhantomData))
+        deserializer.deserialize_any(OperationSeqVisitor(PhantomData))
     }
 }
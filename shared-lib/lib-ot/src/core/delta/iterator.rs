@@ -3,7 +3,8 @@ use crate::{
     core::{Attributes, Delta, Interval, Operation, NEW_LINE},
     rich_text::RichTextAttributes,
 };
-use std::ops::{Deref, DerefMut};
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
 
 pub(crate) const MAX_IV_LEN: usize = i32::MAX as usize;
 
@@ -0,0 +1,124 @@
+use crate::{
+    core::{Attributes, Delta, OperationTransformable},
+    errors::{ErrorBuilder, OTError, OTErrorCode},
+};
+use alloc::{boxed::Box, format, vec, vec::Vec};
+use core::fmt;
+
+pub type DocumentObserver<T> = Box<dyn Fn(&Delta<T>, u64)>;
+
+/// The document delta along with its version, which is increased by one every time a delta is
+/// applied. It only keeps the two of them in step, composing and inverting the deltas is left to
+/// `Delta`.
+pub struct Document<T: Attributes> {
+    delta: Delta<T>,
+    version: u64,
+    observers: Vec<DocumentObserver<T>>,
+}
+
+/// The state of the `Document` at one version, e.g. the periodic snapshot stored with the
+/// version it was taken at.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DocumentSnapshot<T: Attributes> {
+    pub delta: Delta<T>,
+    pub version: u64,
+}
+
+impl<T> fmt::Debug for Document<T>
+where
+    T: Attributes,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Document")
+            .field("delta", &self.delta)
+            .field("version", &self.version)
+            .field("observers", &self.observers.len())
+            .finish()
+    }
+}
+
+impl<T> core::default::Default for Document<T>
+where
+    T: Attributes,
+{
+    fn default() -> Self {
+        Self::new(Delta::new())
+    }
+}
+
+impl<T> Document<T>
+where
+    T: Attributes,
+{
+    /// Creates the document of the delta at version zero.
+    pub fn new(delta: Delta<T>) -> Self {
+        Self {
+            delta,
+            version: 0,
+            observers: vec![],
+        }
+    }
+
+    pub fn delta(&self) -> &Delta<T> {
+        &self.delta
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Applies the `delta` to the document and returns its inverse, which undoes it when applied
+    /// next. The version is increased on every apply, including the undo and the redo, so it
+    /// never goes back. Returns the `IncompatibleLength` error if the `delta` isn't based on the
+    /// document, and the document is left unchanged on error. The observers are notified after the
+    /// `delta` is applied.
+    pub fn apply(&mut self, delta: &Delta<T>) -> Result<Delta<T>, OTError> {
+        if delta.utf16_base_len != self.delta.utf16_target_len {
+            return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength)
+                .msg(format!(
+                    "Expected: {}, received: {}",
+                    self.delta.utf16_target_len, delta.utf16_base_len
+                ))
+                .build());
+        }
+
+        // The `invert` returns the empty delta for the empty base, but the delta applied to the empty
+        // document only inserts, so it's undone by deleting everything it inserted.
+        let inverted = match self.delta.is_empty() {
+            true => {
+                let mut inverted = Delta::default();
+                inverted.delete(delta.utf16_target_len);
+                inverted
+            }
+            false => delta.invert(&self.delta),
+        };
+        self.delta = self.delta.compose(delta)?;
+        self.version += 1;
+        self.observers.iter().for_each(|observer| observer(delta, self.version));
+        Ok(inverted)
+    }
+
+    /// Registers the `observer` that is called with the applied delta and the new version after
+    /// every `apply`, e.g. to refresh the UI. The observers are called synchronously in the order
+    /// they are registered. They only see the delta, not the document, so they can't change the
+    /// document while it's notifying them.
+    pub fn subscribe<F>(&mut self, observer: F)
+    where
+        F: Fn(&Delta<T>, u64) + 'static,
+    {
+        self.observers.push(Box::new(observer));
+    }
+
+    pub fn snapshot(&self) -> DocumentSnapshot<T> {
+        DocumentSnapshot {
+            delta: self.delta.clone(),
+            version: self.version,
+        }
+    }
+
+    /// Restores the document to the `snapshot`, both the content and the version.
+    pub fn restore(&mut self, snapshot: DocumentSnapshot<T>) {
+        self.delta = snapshot.delta;
+        self.version = snapshot.version;
+    }
+}
@@ -1,13 +1,75 @@
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeSet,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+};
+use core::{
+    fmt,
+    fmt::Formatter,
+    hash::{Hash, Hasher},
+};
 use serde::{de, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
-use std::{fmt, fmt::Formatter};
-
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct FlowyStr(pub String);
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The text of an insert. It either owns its `String` or shares the `Arc<str>` handed out by the
+/// `StrInterner`, so the identical inserts across a large delta share one allocation. Any change
+/// to a shared string copies it into an owned one first.
+#[derive(Clone, Debug)]
+pub struct FlowyStr(Repr);
+
+#[derive(Clone, Debug)]
+enum Repr {
+    Owned(String),
+    Shared(Arc<str>),
+}
 
 impl FlowyStr {
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Owned(s) => s,
+            Repr::Shared(s) => s,
+        }
+    }
+
+    /// Returns true if the string is shared with the other inserts, see `StrInterner`.
+    pub fn is_shared(&self) -> bool {
+        matches!(self.0, Repr::Shared(_))
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        self.to_mut().push_str(s);
+    }
+
+    /// Returns the capacity of the owned string, or the length of the shared one.
+    pub fn capacity(&self) -> usize {
+        match &self.0 {
+            Repr::Owned(s) => s.capacity(),
+            Repr::Shared(s) => s.len(),
+        }
+    }
+
+    /// Releases the unused capacity of the owned string. The shared string has none.
+    pub fn shrink_to_fit(&mut self) {
+        if let Repr::Owned(s) = &mut self.0 {
+            s.shrink_to_fit();
+        }
+    }
+
+    fn to_mut(&mut self) -> &mut String {
+        if let Repr::Shared(s) = &self.0 {
+            self.0 = Repr::Owned(s.to_string());
+        }
+        match &mut self.0 {
+            Repr::Owned(s) => s,
+            Repr::Shared(_) => unreachable!(),
+        }
+    }
+
     // https://stackoverflow.com/questions/2241348/what-is-unicode-utf-8-utf-16
     pub fn utf16_size(&self) -> usize {
-        count_utf16_code_units(&self.0)
+        count_utf16_code_units(self)
     }
 
     pub fn utf16_code_unit_iter(&self) -> Utf16CodeUnitIterator {
@@ -33,56 +95,122 @@ impl FlowyStr {
         }
     }
 
+    /// Returns the number of the grapheme clusters, i.e. the characters the user sees. The ZWJ
+    /// emoji sequence or the letter followed by the combining accent is counted as one.
+    pub fn grapheme_count(&self) -> usize {
+        self.as_str().graphemes(true).count()
+    }
+
+    /// Returns the UTF-16 offset right after the first `n` grapheme clusters, which is always a
+    /// cluster boundary. The `n` greater than the number of clusters returns the `utf16_size`.
+    pub fn grapheme_utf16_offset(&self, n: usize) -> usize {
+        self.as_str().graphemes(true).take(n).map(count_utf16_code_units).sum()
+    }
+
     #[allow(dead_code)]
     fn utf16_code_point_iter(&self) -> FlowyUtf16CodePointIterator {
         FlowyUtf16CodePointIterator::new(self, 0)
     }
 }
 
-impl std::ops::Deref for FlowyStr {
-    type Target = String;
+impl core::ops::Deref for FlowyStr {
+    type Target = str;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        self.as_str()
+    }
+}
+
+impl core::cmp::PartialEq for FlowyStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
     }
 }
 
-impl std::ops::DerefMut for FlowyStr {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+impl core::cmp::Eq for FlowyStr {}
+
+impl Hash for FlowyStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
     }
 }
 
-impl std::convert::From<String> for FlowyStr {
+impl core::convert::From<String> for FlowyStr {
     fn from(s: String) -> Self {
-        FlowyStr(s)
+        FlowyStr(Repr::Owned(s))
     }
 }
 
-impl std::convert::From<&str> for FlowyStr {
+impl core::convert::From<&str> for FlowyStr {
     fn from(s: &str) -> Self {
         s.to_owned().into()
     }
 }
 
-impl std::fmt::Display for FlowyStr {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.0)
+impl core::convert::From<Arc<str>> for FlowyStr {
+    fn from(s: Arc<str>) -> Self {
+        FlowyStr(Repr::Shared(s))
     }
 }
 
-impl std::ops::Add<&str> for FlowyStr {
+impl core::convert::From<FlowyStr> for String {
+    fn from(s: FlowyStr) -> Self {
+        match s.0 {
+            Repr::Owned(s) => s,
+            Repr::Shared(s) => s.to_string(),
+        }
+    }
+}
+
+impl core::fmt::Display for FlowyStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self)
+    }
+}
+
+impl core::ops::Add<&str> for FlowyStr {
     type Output = FlowyStr;
 
-    fn add(self, rhs: &str) -> FlowyStr {
-        let new_value = self.0 + rhs;
-        new_value.into()
+    fn add(mut self, rhs: &str) -> FlowyStr {
+        self.push_str(rhs);
+        self
     }
 }
 
-impl std::ops::AddAssign<&str> for FlowyStr {
+impl core::ops::AddAssign<&str> for FlowyStr {
     fn add_assign(&mut self, rhs: &str) {
-        self.0 += rhs;
+        self.push_str(rhs);
+    }
+}
+
+/// Hands out one shared `Arc<str>` for every distinct string, e.g. the spaces and the common
+/// words that repeat across a large document. See `Delta::intern`.
+#[derive(Debug, Default)]
+pub struct StrInterner {
+    strs: BTreeSet<Arc<str>>,
+}
+
+impl StrInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, s: &str) -> FlowyStr {
+        if let Some(shared) = self.strs.get(s) {
+            return shared.clone().into();
+        }
+        let shared: Arc<str> = s.into();
+        self.strs.insert(shared.clone());
+        shared.into()
+    }
+
+    /// Returns the number of the distinct strings.
+    pub fn len(&self) -> usize {
+        self.strs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strs.is_empty()
     }
 }
 
@@ -91,7 +219,7 @@ impl Serialize for FlowyStr {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.0)
+        serializer.serialize_str(self)
     }
 }
 
@@ -178,7 +306,7 @@ impl<'a> FlowyUtf16CodePointIterator<'a> {
 }
 
 use crate::core::Interval;
-use std::str;
+use core::str;
 
 impl<'a> Iterator for FlowyUtf16CodePointIterator<'a> {
     type Item = String;
@@ -226,7 +354,8 @@ pub fn len_utf8_from_first_byte(b: u8) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::core::{FlowyStr, Interval};
+    use crate::core::{FlowyStr, Interval, StrInterner};
+    use alloc::string::{String, ToString};
 
     #[test]
     fn flowy_str_code_unit() {
@@ -273,6 +402,40 @@ mod tests {
         assert_eq!(output2, "\n");
     }
 
+    #[test]
+    fn flowy_str_grapheme_count() {
+        // The family emoji is five scalar values joined by the ZWJ.
+        let s: FlowyStr = "👨\u{200d}👩\u{200d}👧!".into();
+        assert_eq!(s.chars().count(), 6);
+        assert_eq!(s.utf16_size(), 9);
+        assert_eq!(s.grapheme_count(), 2);
+        assert_eq!(s.grapheme_utf16_offset(1), 8);
+        assert_eq!(s.grapheme_utf16_offset(3), 9);
+
+        let s: FlowyStr = "e\u{301}a".into();
+        assert_eq!(s.chars().count(), 3);
+        assert_eq!(s.grapheme_count(), 2);
+        assert_eq!(s.grapheme_utf16_offset(1), 2);
+    }
+
+    #[test]
+    fn flowy_str_interned() {
+        let mut interner = StrInterner::new();
+        let a = interner.intern("abc");
+        let b = interner.intern("abc");
+        assert!(a.is_shared());
+        assert_eq!(a.as_ptr(), b.as_ptr());
+        assert_eq!(interner.len(), 1);
+        assert_eq!(a, FlowyStr::from("abc"));
+
+        // Appending copies the shared string, the others still see the old one.
+        let mut c = b.clone();
+        c += "d";
+        assert!(!c.is_shared());
+        assert_eq!(c.as_str(), "abcd");
+        assert_eq!(a.as_str(), "abc");
+    }
+
     #[test]
     fn flowy_str_sub_str_in_english() {
         let s: FlowyStr = "ab".into();
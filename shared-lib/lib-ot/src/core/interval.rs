@@ -1,4 +1,4 @@
-use std::{
+use core::{
     cmp::{max, min},
     fmt,
     ops::{Range, RangeInclusive, RangeTo, RangeToInclusive},
@@ -112,7 +112,7 @@ impl Interval {
     }
 }
 
-impl std::default::Default for Interval {
+impl core::default::Default for Interval {
     fn default() -> Self {
         Interval::new(0, 0)
     }
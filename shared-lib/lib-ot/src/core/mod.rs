@@ -1,10 +1,12 @@
 mod delta;
+mod document;
 mod flowy_str;
 mod interval;
 mod operation;
 
 use crate::errors::OTError;
 pub use delta::*;
+pub use document::*;
 pub use flowy_str::*;
 pub use interval::*;
 pub use operation::*;
@@ -1,7 +1,9 @@
 use crate::{
-    core::{Attributes, Operation, PlainTextAttributes},
+    core::{Attributes, Embed, Operation, PlainTextAttributes},
+    errors::{ErrorBuilder, OTError, OTErrorCode},
     rich_text::RichTextAttributes,
 };
+use alloc::format;
 
 pub type RichTextOpBuilder = OpBuilder<RichTextAttributes>;
 pub type PlainTextOpBuilder = OpBuilder<PlainTextAttributes>;
@@ -34,18 +36,52 @@ where
         OpBuilder::new(Operation::Insert(s.into()))
     }
 
+    pub fn embed(embed: Embed) -> OpBuilder<T> {
+        OpBuilder::new(Operation::Insert(embed.into()))
+    }
+
+    pub fn retain_with_attributes(n: usize, attrs: T) -> OpBuilder<T> {
+        OpBuilder::retain(n).attributes(attrs)
+    }
+
+    pub fn insert_with_attributes(s: &str, attrs: T) -> OpBuilder<T> {
+        OpBuilder::insert(s).attributes(attrs)
+    }
+
     pub fn attributes(mut self, attrs: T) -> OpBuilder<T> {
         self.attrs = attrs;
         self
     }
 
+    /// Builds the operation with the attributes. The delete doesn't carry any attributes, so
+    /// building a delete with non-empty attributes is a bug and panics in debug builds.
     pub fn build(self) -> Operation<T> {
         let mut operation = self.ty;
         match &mut operation {
-            Operation::Delete(_) => {}
+            Operation::Delete(_) => debug_assert!(self.attrs.is_empty(), "The delete can't carry attributes"),
             Operation::Retain(retain) => retain.attributes = self.attrs,
             Operation::Insert(insert) => insert.attributes = self.attrs,
         }
         operation
     }
+
+    /// Builds the operation like `build`, but returns the `InvalidOperation` error instead of the
+    /// empty operation, e.g. `retain(0)` or `insert("")`, or the delete with attributes. It's for the
+    /// call sites that take the operation from the outside and shouldn't let a bad one into the
+    /// delta.
+    pub fn build_checked(self) -> Result<Operation<T>, OTError> {
+        if matches!(self.ty, Operation::Delete(_)) && !self.attrs.is_empty() {
+            return Err(ErrorBuilder::new(OTErrorCode::InvalidOperation)
+                .msg("The delete can't carry attributes")
+                .build());
+        }
+
+        let operation = self.build();
+        if operation.is_empty() {
+            return Err(ErrorBuilder::new(OTErrorCode::InvalidOperation)
+                .msg(format!("The operation is empty: {}", operation))
+                .build());
+        }
+        Ok(operation)
+    }
 }
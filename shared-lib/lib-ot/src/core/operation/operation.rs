@@ -1,14 +1,20 @@
 use crate::{
-    core::{FlowyStr, Interval, OpBuilder, OperationTransformable},
+    core::{Delta, FlowyStr, Interval, OpBuilder, OperationTransformable},
     errors::OTError,
 };
-use serde::{Deserialize, Serialize, __private::Formatter};
-use std::{
-    cmp::min,
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    cmp::{max, min, Ordering},
     fmt,
     fmt::Debug,
     ops::{Deref, DerefMut},
 };
+use serde::{__private::Formatter, Deserialize, Serialize};
 
 pub trait Attributes: fmt::Display + Eq + PartialEq + Default + Clone + Debug + OperationTransformable {
     fn is_empty(&self) -> bool;
@@ -17,9 +23,65 @@ pub trait Attributes: fmt::Display + Eq + PartialEq + Default + Clone + Debug +
     fn remove_empty(&mut self);
 
     fn extend_other(&mut self, other: Self);
+
+    /// Moves the attributes behind a shared pointer, so their clones share them instead of copying,
+    /// see `Delta::compact`. The attributes that are cheap to clone don't need it, so nothing
+    /// happens by default.
+    fn share(&mut self) {}
+
+    /// Transforms the `other` attributes that were applied concurrently with these attributes to
+    /// the same range, and returns the attributes to apply after these ones. If `priority` is
+    /// true, these attributes win, so every key set by them, including the removals, is dropped
+    /// from `other`. Otherwise `other` wins and is returned as it is.
+    fn transform_with_priority(&self, other: &Self, priority: bool) -> Self;
+
+    /// Splits the attributes into the ones that apply to the line, e.g. the header, which are stored
+    /// on the newline that ends the line, and the rest. `Delta::format` and `Delta::each_line` route
+    /// the attributes by it. None of the attributes applies to the line by default.
+    fn split_block(&self) -> (Self, Self) {
+        (Self::default(), self.clone())
+    }
+
+    /// Returns the gravity hint of the insert that carries these attributes, see `Gravity`. The
+    /// attributes without the hint return `None`.
+    fn gravity(&self) -> Option<Gravity> {
+        None
+    }
+}
+
+/// The hint that decides the order of the concurrent inserts at the same position when they are
+/// transformed, e.g. to keep the text inserted by the autocomplete next to the mention it belongs
+/// to. The insert with the `Left` gravity is placed before the other insert, and the one with
+/// the `Right` gravity after it. The gravity takes precedence over the `priority` of the
+/// transform, which only decides the order of the inserts with the same gravity.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Gravity {
+    Left,
+    Right,
+}
+
+impl Gravity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Gravity::Left => "left",
+            Gravity::Right => "right",
+        }
+    }
+}
+
+impl core::str::FromStr for Gravity {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Gravity, Self::Err> {
+        match s {
+            "left" => Ok(Gravity::Left),
+            "right" => Ok(Gravity::Right),
+            _ => Err(()),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum Operation<T: Attributes> {
     Delete(usize),
     Retain(Retain<T>),
@@ -46,6 +108,16 @@ where
         }
     }
 
+    /// Borrows the attributes of the operation without cloning them. Returns `None` for the delete,
+    /// which doesn't carry any attributes.
+    pub fn attributes_ref(&self) -> Option<&T> {
+        match self {
+            Operation::Delete(_) => None,
+            Operation::Retain(retain) => Some(&retain.attributes),
+            Operation::Insert(insert) => Some(&insert.attributes),
+        }
+    }
+
     pub fn set_attributes(&mut self, attributes: T) {
         match self {
             Operation::Delete(_) => log::error!("Delete should not contains attributes"),
@@ -54,8 +126,23 @@ where
         }
     }
 
+    /// Appends `s` to the text of the insert in place, keeping its attributes, e.g. to grow one
+    /// insert while tokenizing the input. Returns false without changing anything if the operation
+    /// isn't a text insert, the embed can't be extended.
+    ///
+    /// The operation in a delta must not be changed this way, or the lengths of the delta go stale.
+    pub fn append_text(&mut self, s: &str) -> bool {
+        match self {
+            Operation::Insert(insert) if insert.embed.is_none() => {
+                insert.s.push_str(s);
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub fn has_attribute(&self) -> bool {
-        !self.get_attributes().is_empty()
+        self.attributes_ref().map_or(false, |attributes| !attributes.is_empty())
     }
 
     pub fn len(&self) -> usize {
@@ -66,40 +153,79 @@ where
         }
     }
 
+    /// Returns the length of the insert in UTF-8 bytes, e.g. to splice the UTF-8 buffer. The retain
+    /// and the delete return `None`: their lengths are counted in UTF-16 code units of the
+    /// document they are applied to, and can't be converted to bytes without that document.
+    pub fn byte_length(&self) -> Option<usize> {
+        match self {
+            Operation::Insert(insert) => Some(insert.s.len()),
+            _ => None,
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
-    #[allow(dead_code)]
+    /// Splits the operation at the `index`, which is counted in UTF-16 code units. The attributes
+    /// are kept on both halves. If the `index` is zero or not less than the length of the operation,
+    /// the whole operation is returned on one side and `None` on the other.
     pub fn split(&self, index: usize) -> (Option<Operation<T>>, Option<Operation<T>>) {
-        debug_assert!(index < self.len());
-        let left;
-        let right;
+        if index == 0 {
+            return (None, Some(self.clone()));
+        }
+
+        if index >= self.len() {
+            return (Some(self.clone()), None);
+        }
+
         match self {
-            Operation::Delete(n) => {
-                left = Some(OpBuilder::<T>::delete(index).build());
-                right = Some(OpBuilder::<T>::delete(*n - index).build());
-            }
-            Operation::Retain(retain) => {
-                left = Some(OpBuilder::<T>::delete(index).build());
-                right = Some(OpBuilder::<T>::delete(retain.n - index).build());
-            }
-            Operation::Insert(insert) => {
-                let attributes = self.get_attributes();
-                left = Some(
-                    OpBuilder::<T>::insert(&insert.s[0..index])
-                        .attributes(attributes.clone())
+            Operation::Delete(n) => (
+                Some(OpBuilder::<T>::delete(index).build()),
+                Some(OpBuilder::<T>::delete(*n - index).build()),
+            ),
+            Operation::Retain(retain) => (
+                Some(
+                    OpBuilder::<T>::retain(index)
+                        .attributes(retain.attributes.clone())
                         .build(),
-                );
-                right = Some(
-                    OpBuilder::<T>::insert(&insert.s[index..insert.utf16_size()])
-                        .attributes(attributes)
+                ),
+                Some(
+                    OpBuilder::<T>::retain(retain.n - index)
+                        .attributes(retain.attributes.clone())
                         .build(),
-                );
+                ),
+            ),
+            Operation::Insert(insert) => {
+                let left = insert.s.sub_str(Interval::new(0, index)).unwrap_or_default();
+                let right = insert
+                    .s
+                    .sub_str(Interval::new(index, insert.utf16_size()))
+                    .unwrap_or_default();
+                (
+                    Some(
+                        OpBuilder::<T>::insert(&left)
+                            .attributes(insert.attributes.clone())
+                            .build(),
+                    ),
+                    Some(
+                        OpBuilder::<T>::insert(&right)
+                            .attributes(insert.attributes.clone())
+                            .build(),
+                    ),
+                )
             }
         }
+    }
 
-        (left, right)
+    /// Splits the insert after the first `n` grapheme clusters, so the user-perceived character,
+    /// e.g. the ZWJ emoji sequence, is never cut in half. The retain and the delete don't know the
+    /// text they cover, so they are split at `n` code units just like `split`.
+    pub fn split_at_grapheme(&self, n: usize) -> (Option<Operation<T>>, Option<Operation<T>>) {
+        match self {
+            Operation::Insert(insert) => self.split(insert.s.grapheme_utf16_offset(n)),
+            _ => self.split(n),
+        }
     }
 
     pub fn shrink(&self, interval: Interval) -> Option<Operation<T>> {
@@ -111,6 +237,12 @@ where
             Operation::Insert(insert) => {
                 if interval.start > insert.utf16_size() {
                     OpBuilder::insert("").build()
+                } else if insert.embed.is_some() {
+                    // The embed is atomic, it can't be cut into pieces.
+                    match interval.is_empty() {
+                        true => OpBuilder::insert("").build(),
+                        false => self.clone(),
+                    }
                 } else {
                     let s = insert.s.sub_str(interval).unwrap_or_else(|| "".to_owned());
                     OpBuilder::insert(&s).attributes(insert.attributes.clone()).build()
@@ -145,6 +277,61 @@ where
         false
     }
 
+    pub fn as_insert(&self) -> Option<&Insert<T>> {
+        match self {
+            Operation::Insert(insert) => Some(insert),
+            _ => None,
+        }
+    }
+
+    pub fn as_retain(&self) -> Option<&Retain<T>> {
+        match self {
+            Operation::Retain(retain) => Some(retain),
+            _ => None,
+        }
+    }
+
+    /// Returns the length of the delete.
+    pub fn as_delete(&self) -> Option<usize> {
+        match self {
+            Operation::Delete(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Like `append_text`, changing the length of the operation in a delta makes the lengths of
+    /// the delta go stale.
+    pub fn as_insert_mut(&mut self) -> Option<&mut Insert<T>> {
+        match self {
+            Operation::Insert(insert) => Some(insert),
+            _ => None,
+        }
+    }
+
+    pub fn as_retain_mut(&mut self) -> Option<&mut Retain<T>> {
+        match self {
+            Operation::Retain(retain) => Some(retain),
+            _ => None,
+        }
+    }
+
+    pub fn as_delete_mut(&mut self) -> Option<&mut usize> {
+        match self {
+            Operation::Delete(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the operation has no effect, which is only the case for the plain retain.
+    /// The retain with attributes formats the text it covers, so it's not a noop, use
+    /// `is_retain` to check the kind of the operation only.
+    pub fn is_noop(&self) -> bool {
+        match self {
+            Operation::Retain(retain) => retain.is_plain(),
+            _ => false,
+        }
+    }
+
     pub fn is_plain(&self) -> bool {
         match self {
             Operation::Delete(_) => true,
@@ -152,6 +339,108 @@ where
             Operation::Insert(insert) => insert.is_plain(),
         }
     }
+
+    /// Orders the operations by their kinds: insert < retain < delete. It's the order of the
+    /// operations at the same position of the canonical delta, which matches `transform`: the
+    /// insert is always handled before the retain or delete of the other side at the same
+    /// position, so it's placed first. The adjacent insert and delete commute, the result is the
+    /// same, so the delta keeps the insert before the delete. The operations of the same kind are
+    /// equal, which keeps their order in the stable sort.
+    pub fn canonical_cmp(&self, other: &Self) -> Ordering {
+        fn rank<T: Attributes>(op: &Operation<T>) -> u8 {
+            match op {
+                Operation::Insert(_) => 0,
+                Operation::Retain(_) => 1,
+                Operation::Delete(_) => 2,
+            }
+        }
+        rank(self).cmp(&rank(other))
+    }
+
+    /// Transforms two operations that happened concurrently and produces two deltas a' and b'.
+    ///  (a', b') = a.transform(b, priority)
+    ///  a.compose(b') = b.compose(a')
+    ///
+    /// The transformed result can't always be represented by a single operation: the insert that
+    /// goes second must skip the one that goes first, e.g. b' of two inserts at the same position
+    /// is a retain followed by the insert, and so is the transformed delete against an insert.
+    /// The operation has no position to carry that offset, so deltas are returned. The operation
+    /// with the shorter base length is padded with a retain, which means the rest of the document
+    /// is kept as it is. The padded deltas have the same base length, so it can't fail.
+    ///
+    /// If `priority` is true, the insert of `self` will be placed before the insert of `other`
+    /// when both of them insert at the same position.
+    pub fn transform(&self, other: &Self, priority: bool) -> (Delta<T>, Delta<T>) {
+        let mut a = Delta::new();
+        a.add(self.clone());
+        let mut b = Delta::new();
+        b.add(other.clone());
+
+        let base_len = max(a.utf16_base_len, b.utf16_base_len);
+        a.retain(base_len - a.utf16_base_len, T::default());
+        b.retain(base_len - b.utf16_base_len, T::default());
+
+        let transformed = if priority {
+            a.transform(&b)
+        } else {
+            b.transform(&a).map(|(b_prime, a_prime)| (a_prime, b_prime))
+        };
+        transformed.unwrap_or_else(|e| unreachable!("The padded operations don't line up: {}", e))
+    }
+
+    /// Combines the operation with the adjacent `other` into a single operation. The combinable
+    /// cases are:
+    /// * two inserts with the same attributes, the strings are concatenated. The embed is never
+    ///   combined.
+    /// * two deletes, the lengths are added up.
+    /// * two retains with the same attributes, the lengths are added up.
+    ///
+    /// Returns `None` for any other pair, which has to be kept as two operations.
+    pub fn compose(&self, other: &Self) -> Option<Operation<T>> {
+        match (self, other) {
+            (Operation::Insert(insert), Operation::Insert(other_insert))
+                if insert.embed.is_none()
+                    && other_insert.embed.is_none()
+                    && insert.attributes == other_insert.attributes =>
+            {
+                let s = format!("{}{}", insert.s.as_str(), other_insert.s.as_str());
+                Some(OpBuilder::insert(&s).attributes(insert.attributes.clone()).build())
+            }
+            (Operation::Delete(n), Operation::Delete(other_n)) => Some(OpBuilder::delete(n + other_n).build()),
+            (Operation::Retain(retain), Operation::Retain(other_retain))
+                if retain.attributes == other_retain.attributes =>
+            {
+                Some(
+                    OpBuilder::retain(retain.n + other_retain.n)
+                        .attributes(retain.attributes.clone())
+                        .build(),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the attributes that restore the `base` attributes of the range this operation was
+    /// applied to, i.e. the inverse of its formatting. The keys set by the operation take the value
+    /// of `base` back, and the keys that don't exist in `base` are marked as removed. The delete
+    /// doesn't format anything, so it returns the empty attributes.
+    pub fn invert_attributes(&self, base: &T) -> T {
+        match self.attributes_ref() {
+            None => T::default(),
+            Some(attributes) => attributes.invert(base),
+        }
+    }
+
+    /// Merges the operations into the canonical form and returns them as a new `Vec`, e.g. for the
+    /// tools that work on the raw operations. Like `Delta::normalize`, the adjacent operations are
+    /// merged, the inserts are moved before the deletes between two retains and the empty
+    /// operations are dropped.
+    pub fn merge_run(ops: &[Operation<T>]) -> Vec<Operation<T>> {
+        let mut delta = Delta::new();
+        delta.ops = ops.to_vec();
+        delta.normalize();
+        delta.ops
+    }
 }
 
 impl<T> fmt::Display for Operation<T>
@@ -176,7 +465,7 @@ where
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Retain<T: Attributes> {
     // #[serde(rename(serialize = "retain", deserialize = "retain"))]
     pub n: usize,
@@ -221,7 +510,7 @@ where
     }
 }
 
-impl<T> std::convert::From<usize> for Retain<T>
+impl<T> core::convert::From<usize> for Retain<T>
 where
     T: Attributes,
 {
@@ -253,13 +542,45 @@ where
     }
 }
 
+/// Every embed takes the place of one object replacement character in the document, so it
+/// counts as length 1 and it will never be split.
+pub const EMBED_PLACEHOLDER: &str = "\u{fffc}";
+
+/// The non-text object, e.g. image, divider or mention, inserted into the document. It's
+/// serialized as `{"insert": {"image": "https://..."}}`. The value is any JSON, e.g. the
+/// `{"id": "...", "name": "..."}` of the mention.
 #[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Embed {
+    pub ty: String,
+    pub value: serde_json::Value,
+}
+
+impl Embed {
+    pub fn new<K: Into<String>, V: Into<serde_json::Value>>(ty: K, value: V) -> Self {
+        Self {
+            ty: ty.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl core::hash::Hash for Embed {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.ty.hash(state);
+        self.value.to_string().hash(state);
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Insert<T: Attributes> {
     // #[serde(rename(serialize = "insert", deserialize = "insert"))]
     pub s: FlowyStr,
 
     // #[serde(skip_serializing_if = "is_empty")]
     pub attributes: T,
+
+    // The s is the EMBED_PLACEHOLDER if the embed is not None.
+    pub embed: Option<Embed>,
 }
 
 impl<T> fmt::Display for Insert<T>
@@ -267,7 +588,10 @@ where
     T: Attributes,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let mut s = self.s.clone();
+        let mut s = self.s.to_string();
+        if let Some(embed) = &self.embed {
+            s = format!("{{{}: {}}}", embed.ty, embed.value);
+        }
         if s.ends_with('\n') {
             s.pop();
             if s.is_empty() {
@@ -291,8 +615,47 @@ where
         self.s.utf16_size()
     }
 
+    /// Returns the length of the UTF-8 encoded string. Use `num_utf16_units` to get the length
+    /// that lines up with the offsets of the JavaScript clients.
+    pub fn num_bytes(&self) -> usize {
+        self.s.len()
+    }
+
+    /// Returns the length in UTF-16 code units, the same as `utf16_size`.
+    pub fn num_utf16_units(&self) -> usize {
+        self.utf16_size()
+    }
+
+    /// Splits the insert after the first `index` characters, i.e. Unicode scalar values, so the
+    /// string is always cut at a char boundary. The attributes are kept on both halves. The index
+    /// beyond the end puts the whole insert on the left. The embed can't be cut, so it's put on
+    /// the right if the index is zero, otherwise on the left.
+    pub fn split_at_char(&self, index: usize) -> (Insert<T>, Insert<T>) {
+        let offset = match &self.embed {
+            Some(_) if index == 0 => 0,
+            Some(_) => self.s.len(),
+            None => self
+                .s
+                .char_indices()
+                .nth(index)
+                .map_or(self.s.len(), |(offset, _)| offset),
+        };
+        let (left, right) = self.s.split_at(offset);
+        let split = |s: &str| Insert {
+            s: s.into(),
+            attributes: self.attributes.clone(),
+            embed: self.embed.clone().filter(|_| !s.is_empty()),
+        };
+        (split(left), split(right))
+    }
+
+    /// Returns the number of the user-perceived characters, see `FlowyStr::grapheme_count`.
+    pub fn num_graphemes(&self) -> usize {
+        self.s.grapheme_count()
+    }
+
     pub fn merge_or_new_op(&mut self, s: &str, attributes: T) -> Option<Operation<T>> {
-        if self.attributes == attributes {
+        if self.attributes == attributes && self.embed.is_none() {
             self.s += s;
             None
         } else {
@@ -303,9 +666,25 @@ where
     pub fn is_plain(&self) -> bool {
         self.attributes.is_empty()
     }
+
+    /// Returns true if the insert is an embed, e.g. an image, rather than the text.
+    pub fn is_embed(&self) -> bool {
+        self.embed.is_some()
+    }
+
+    /// Returns the type of the embed, e.g. `image`, or None if the insert is the text.
+    pub fn embed_type(&self) -> Option<&str> {
+        self.embed.as_ref().map(|embed| embed.ty.as_str())
+    }
+
+    /// Returns the value of the embed, e.g. the url of the image, or None if the insert is the
+    /// text.
+    pub fn embed_value(&self) -> Option<&serde_json::Value> {
+        self.embed.as_ref().map(|embed| &embed.value)
+    }
 }
 
-impl<T> std::convert::From<String> for Insert<T>
+impl<T> core::convert::From<String> for Insert<T>
 where
     T: Attributes,
 {
@@ -313,11 +692,12 @@ where
         Insert {
             s: s.into(),
             attributes: T::default(),
+            embed: None,
         }
     }
 }
 
-impl<T> std::convert::From<&str> for Insert<T>
+impl<T> core::convert::From<&str> for Insert<T>
 where
     T: Attributes,
 {
@@ -326,7 +706,7 @@ where
     }
 }
 
-impl<T> std::convert::From<FlowyStr> for Insert<T>
+impl<T> core::convert::From<FlowyStr> for Insert<T>
 where
     T: Attributes,
 {
@@ -334,11 +714,25 @@ where
         Insert {
             s,
             attributes: T::default(),
+            embed: None,
+        }
+    }
+}
+
+impl<T> core::convert::From<Embed> for Insert<T>
+where
+    T: Attributes,
+{
+    fn from(embed: Embed) -> Self {
+        Insert {
+            s: EMBED_PLACEHOLDER.into(),
+            attributes: T::default(),
+            embed: Some(embed),
         }
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
 pub struct PlainTextAttributes();
 impl fmt::Display for PlainTextAttributes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -354,6 +748,10 @@ impl Attributes for PlainTextAttributes {
     fn remove_empty(&mut self) {}
 
     fn extend_other(&mut self, _other: Self) {}
+
+    fn transform_with_priority(&self, other: &Self, _priority: bool) -> Self {
+        other.clone()
+    }
 }
 
 impl OperationTransformable for PlainTextAttributes {
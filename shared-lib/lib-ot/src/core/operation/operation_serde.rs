@@ -1,11 +1,12 @@
-use crate::core::{Attributes, FlowyStr, Insert, Operation, Retain};
+use crate::core::{Attributes, Embed, FlowyStr, Insert, Operation, Retain};
+use alloc::string::String;
+use core::{fmt, marker::PhantomData};
 use serde::{
     de,
     de::{MapAccess, SeqAccess, Visitor},
     ser::SerializeMap,
     Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::{fmt, marker::PhantomData};
 
 impl<T> Serialize for Operation<T>
 where
@@ -54,8 +55,8 @@ where
             {
                 let mut operation = None;
                 let mut attributes = None;
-                while let Some(key) = map.next_key()? {
-                    match key {
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
                         "delete" => {
                             if operation.is_some() {
                                 return Err(de::Error::duplicate_field("operation"));
@@ -73,7 +74,7 @@ where
                             if operation.is_some() {
                                 return Err(de::Error::duplicate_field("operation"));
                             }
-                            let i: String = map.next_value()?;
+                            let i: InsertValue = map.next_value()?;
                             operation = Some(Operation::<T>::Insert(i.into()));
                         }
                         "attributes" => {
@@ -83,7 +84,12 @@ where
                             let map: T = map.next_value()?;
                             attributes = Some(map);
                         }
-                        _ => panic!(),
+                        _ => {
+                            return Err(de::Error::unknown_field(
+                                &key,
+                                &["insert", "retain", "delete", "attributes"],
+                            ))
+                        }
                     }
                 }
                 match operation {
@@ -169,8 +175,8 @@ where
             {
                 let mut len: Option<usize> = None;
                 let mut attributes: Option<T> = None;
-                while let Some(key) = map.next_key()? {
-                    match key {
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
                         "retain" => {
                             if len.is_some() {
                                 return Err(de::Error::duplicate_field("retain"));
@@ -183,7 +189,7 @@ where
                             }
                             attributes = Some(map.next_value()?);
                         }
-                        _ => panic!(),
+                        _ => return Err(de::Error::unknown_field(&key, FIELDS)),
                     }
                 }
 
@@ -215,7 +221,10 @@ where
     {
         let len = false as usize + 1 + if self.attributes.is_empty() { 0 } else { 1 };
         let mut serde_state = serializer.serialize_struct("Insert", len)?;
-        let _ = serde::ser::SerializeStruct::serialize_field(&mut serde_state, "insert", &self.s)?;
+        match &self.embed {
+            None => serde::ser::SerializeStruct::serialize_field(&mut serde_state, "insert", &self.s)?,
+            Some(embed) => serde::ser::SerializeStruct::serialize_field(&mut serde_state, "insert", embed)?,
+        }
         if !self.attributes.is_empty() {
             let _ = serde::ser::SerializeStruct::serialize_field(&mut serde_state, "attributes", &self.attributes)?;
         }
@@ -248,7 +257,7 @@ where
             where
                 A: SeqAccess<'de>,
             {
-                let s = match serde::de::SeqAccess::next_element::<FlowyStr>(&mut seq)? {
+                let value = match serde::de::SeqAccess::next_element::<InsertValue>(&mut seq)? {
                     Some(val) => val,
                     None => {
                         return Err(de::Error::invalid_length(0, &"struct Insert with 2 elements"));
@@ -262,7 +271,9 @@ where
                     }
                 };
 
-                Ok(Insert::<T> { s, attributes })
+                let mut insert: Insert<T> = value.into();
+                insert.attributes = attributes;
+                Ok(insert)
             }
 
             #[inline]
@@ -270,10 +281,10 @@ where
             where
                 V: MapAccess<'de>,
             {
-                let mut s: Option<FlowyStr> = None;
+                let mut s: Option<InsertValue> = None;
                 let mut attributes: Option<T> = None;
-                while let Some(key) = map.next_key()? {
-                    match key {
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
                         "insert" => {
                             if s.is_some() {
                                 return Err(de::Error::duplicate_field("insert"));
@@ -286,7 +297,7 @@ where
                             }
                             attributes = Some(map.next_value()?);
                         }
-                        _ => panic!(),
+                        _ => return Err(de::Error::unknown_field(&key, FIELDS)),
                     }
                 }
 
@@ -297,13 +308,72 @@ where
                 if attributes.is_none() {
                     return Err(de::Error::missing_field("attributes"));
                 }
-                Ok(Insert::<T> {
-                    s: s.unwrap(),
-                    attributes: attributes.unwrap(),
-                })
+                let mut insert: Insert<T> = s.unwrap().into();
+                insert.attributes = attributes.unwrap();
+                Ok(insert)
             }
         }
         const FIELDS: &[&str] = &["insert", "attributes"];
         serde::Deserializer::deserialize_struct(deserializer, "Insert", FIELDS, InsertVisitor(PhantomData))
     }
 }
+
+// The value of the insert is either a string or an embed object.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum InsertValue {
+    Text(FlowyStr),
+    Embed(Embed),
+}
+
+impl<T> core::convert::From<InsertValue> for Insert<T>
+where
+    T: Attributes,
+{
+    fn from(value: InsertValue) -> Self {
+        match value {
+            InsertValue::Text(s) => s.into(),
+            InsertValue::Embed(embed) => embed.into(),
+        }
+    }
+}
+
+impl Serialize for Embed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(&self.ty, &self.value)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Embed {
+    fn deserialize<D>(deserializer: D) -> Result<Embed, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct EmbedVisitor;
+
+        impl<'de> Visitor<'de> for EmbedVisitor {
+            type Value = Embed;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map with one entry")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+            where
+                V: MapAccess<'de>,
+            {
+                match map.next_entry::<String, serde_json::Value>()? {
+                    None => Err(de::Error::invalid_length(0, &self)),
+                    Some((ty, value)) => Ok(Embed { ty, value }),
+                }
+            }
+        }
+
+        deserializer.deserialize_map(EmbedVisitor)
+    }
+}
@@ -1,6 +1,7 @@
-use std::{fmt, fmt::Debug, str::Utf8Error};
+use alloc::{borrow::ToOwned, format, string::String};
+use core::{fmt, fmt::Debug, str::Utf8Error};
 
-#[derive(thiserror::Error, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct OTError {
     pub code: OTErrorCode,
     pub msg: String,
@@ -15,7 +16,7 @@ macro_rules! static_ot_error {
     };
 }
 
-impl std::convert::From<OTErrorCode> for OTError {
+impl core::convert::From<OTErrorCode> for OTError {
     fn from(code: OTErrorCode) -> Self {
         OTError {
             code: code.clone(),
@@ -48,13 +49,16 @@ impl fmt::Display for OTError {
     }
 }
 
-impl std::convert::From<serde_json::Error> for OTError {
+#[cfg(feature = "std")]
+impl std::error::Error for OTError {}
+
+impl core::convert::From<serde_json::Error> for OTError {
     fn from(error: serde_json::Error) -> Self {
         ErrorBuilder::new(OTErrorCode::SerdeError).error(error).build()
     }
 }
 
-impl std::convert::From<Utf8Error> for OTError {
+impl core::convert::From<Utf8Error> for OTError {
     fn from(error: Utf8Error) -> Self {
         ErrorBuilder::new(OTErrorCode::SerdeError).error(error).build()
     }
@@ -63,10 +67,12 @@ impl std::convert::From<Utf8Error> for OTError {
 #[derive(Debug, Clone)]
 pub enum OTErrorCode {
     IncompatibleLength,
+    ChecksumMismatch,
     ApplyInsertFail,
     ApplyDeleteFail,
     ApplyFormatFail,
     ComposeOperationFail,
+    InvalidOperation,
     IntervalOutOfBound,
     UndoFail,
     RedoFail,
@@ -96,7 +102,7 @@ impl ErrorBuilder {
 
     pub fn error<T>(mut self, msg: T) -> Self
     where
-        T: std::fmt::Debug,
+        T: core::fmt::Debug,
     {
         self.msg = Some(format!("{:?}", msg));
         self
@@ -1,36 +1,113 @@
 #![allow(non_snake_case)]
+#[cfg(feature = "std")]
+use crate::errors::{ErrorBuilder, OTErrorCode};
 use crate::{
     block_attribute,
-    core::{Attributes, Operation, OperationTransformable},
+    core::{Attributes, Gravity, Operation, OperationTransformable},
     errors::OTError,
     ignore_attribute, inline_attribute, list_attribute,
 };
-use lazy_static::lazy_static;
-use std::{
-    collections::{HashMap, HashSet},
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    sync::Arc,
+};
+use core::{
+    convert::TryFrom,
     fmt,
     fmt::Formatter,
+    hash::{Hash, Hasher},
     iter::FromIterator,
 };
+#[cfg(feature = "std")]
+use lazy_static::lazy_static;
+#[cfg(feature = "std")]
+use std::{collections::HashMap, sync::RwLock};
 use strum_macros::Display;
 
 pub type RichTextOperation = Operation<RichTextAttributes>;
 impl RichTextOperation {
     pub fn contain_attribute(&self, attribute: &RichTextAttribute) -> bool {
-        self.get_attributes().contains_key(&attribute.key)
+        self.has_attribute_key(&attribute.key)
+    }
+
+    /// Returns true if the operation has the attribute of the `key`, including the removal of it.
+    pub fn has_attribute_key(&self, key: &RichTextAttributeKey) -> bool {
+        self.attribute_value(key).is_some()
+    }
+
+    /// Peeks the value of the attribute without cloning the attributes.
+    pub fn attribute_value(&self, key: &RichTextAttributeKey) -> Option<&RichTextAttributeValue> {
+        self.attributes_ref().and_then(|attributes| attributes.get(key))
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct RichTextAttributes {
-    pub(crate) inner: HashMap<RichTextAttributeKey, RichTextAttributeValue>,
+    pub(crate) inner: AttributeMap,
+}
+
+/// The map of the attributes. It either owns its `BTreeMap` or shares the `Arc` of the attributes
+/// interned by `Delta::compact`, so the operations with the same format share one map. Any change
+/// to a shared map copies it into an owned one first.
+#[derive(Clone)]
+pub(crate) enum AttributeMap {
+    Owned(BTreeMap<RichTextAttributeKey, RichTextAttributeValue>),
+    Shared(Arc<BTreeMap<RichTextAttributeKey, RichTextAttributeValue>>),
 }
 
-impl std::default::Default for RichTextAttributes {
+impl AttributeMap {
+    fn into_owned(self) -> BTreeMap<RichTextAttributeKey, RichTextAttributeValue> {
+        match self {
+            AttributeMap::Owned(map) => map,
+            AttributeMap::Shared(map) => Arc::try_unwrap(map).unwrap_or_else(|map| (*map).clone()),
+        }
+    }
+}
+
+impl Default for AttributeMap {
     fn default() -> Self {
-        Self {
-            inner: HashMap::with_capacity(0),
+        AttributeMap::Owned(BTreeMap::new())
+    }
+}
+
+impl core::ops::Deref for AttributeMap {
+    type Target = BTreeMap<RichTextAttributeKey, RichTextAttributeValue>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            AttributeMap::Owned(map) => map,
+            AttributeMap::Shared(map) => map,
+        }
+    }
+}
+
+impl core::ops::DerefMut for AttributeMap {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        if let AttributeMap::Shared(map) = self {
+            *self = AttributeMap::Owned((**map).clone());
         }
+        match self {
+            AttributeMap::Owned(map) => map,
+            AttributeMap::Shared(_) => unreachable!(),
+        }
+    }
+}
+
+// The owned and the shared maps are equal if their entries are.
+impl PartialEq for AttributeMap {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for AttributeMap {}
+
+impl fmt::Debug for AttributeMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
     }
 }
 
@@ -47,13 +124,18 @@ pub fn plain_attributes() -> RichTextAttributes {
 
 impl RichTextAttributes {
     pub fn new() -> Self {
-        RichTextAttributes { inner: HashMap::new() }
+        RichTextAttributes::default()
     }
 
     pub fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
 
+    /// Returns true if the map is shared with the other attributes, see `Attributes::share`.
+    pub fn is_shared(&self) -> bool {
+        matches!(self.inner, AttributeMap::Shared(_))
+    }
+
     pub fn add(&mut self, attribute: RichTextAttribute) {
         let RichTextAttribute { key, value, scope: _ } = attribute;
         self.inner.insert(key, value);
@@ -64,18 +146,20 @@ impl RichTextAttributes {
     }
 
     pub fn delete(&mut self, key: &RichTextAttributeKey) {
-        self.inner.insert(key.clone(), RichTextAttributeValue(None));
+        self.inner.insert(key.clone(), RichTextAttributeValue::Null);
     }
 
     pub fn mark_all_as_removed_except(&mut self, attribute: Option<RichTextAttributeKey>) {
         match attribute {
             None => {
-                self.inner.iter_mut().for_each(|(_k, v)| v.0 = None);
+                self.inner
+                    .iter_mut()
+                    .for_each(|(_k, v)| *v = RichTextAttributeValue::Null);
             }
             Some(attribute) => {
                 self.inner.iter_mut().for_each(|(k, v)| {
                     if k != &attribute {
-                        v.0 = None;
+                        *v = RichTextAttributeValue::Null;
                     }
                 });
             }
@@ -86,6 +170,59 @@ impl RichTextAttributes {
         self.inner.retain(|k, _| k != &key);
     }
 
+    /// Returns the block attributes, e.g. the header or the list, which apply to the line and are
+    /// stored on the newline that ends it. See `RichTextAttributeKey::scope`.
+    pub fn block_subset(&self) -> RichTextAttributes {
+        self.subset(AttributeScope::Block)
+    }
+
+    /// Returns the inline attributes, e.g. the bold or the link, which apply to the text itself.
+    /// The attributes of the embeds, e.g. the width of the image, belong to neither subset.
+    pub fn inline_subset(&self) -> RichTextAttributes {
+        self.subset(AttributeScope::Inline)
+    }
+
+    /// Folds the attributes of the runs, e.g. the runs of the selected text, into the attributes
+    /// all of them agree on, along with the keys they don't agree on. The key is mixed if its value
+    /// differs between the runs or some of the runs don't have it, so the toolbar shows it as
+    /// partially applied. No runs fold into the empty attributes.
+    pub fn merge_all<'a, I>(runs: I) -> (RichTextAttributes, BTreeSet<RichTextAttributeKey>)
+    where
+        I: IntoIterator<Item = &'a RichTextAttributes>,
+    {
+        let mut runs = runs.into_iter();
+        let mut common = match runs.next() {
+            Some(attributes) => attributes.clone(),
+            None => return (RichTextAttributes::new(), BTreeSet::new()),
+        };
+        let mut mixed = BTreeSet::new();
+        for attributes in runs {
+            common.inner.retain(|k, v| {
+                let agreed = attributes.inner.get(k) == Some(v);
+                if !agreed {
+                    mixed.insert(k.clone());
+                }
+                agreed
+            });
+            attributes
+                .inner
+                .keys()
+                .filter(|k| !common.inner.contains_key(*k))
+                .for_each(|k| {
+                    mixed.insert(k.clone());
+                });
+        }
+        (common, mixed)
+    }
+
+    fn subset(&self, scope: AttributeScope) -> RichTextAttributes {
+        self.inner
+            .iter()
+            .filter(|(k, _)| k.scope() == scope)
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+
     // pub fn block_attributes_except_header(attributes: &Attributes) -> Attributes
     // {     let mut new_attributes = Attributes::new();
     //     attributes.iter().for_each(|(k, v)| {
@@ -110,6 +247,25 @@ impl RichTextAttributes {
         });
         self.inner = new_attributes;
     }
+
+    /// Returns the attributes that must be applied to turn `self` into `other`. The keys only
+    /// exist in `self` are marked as removed, the keys that are new or changed in `other` take
+    /// the value of `other` and the unchanged keys are omitted.
+    pub fn diff(&self, other: &RichTextAttributes) -> RichTextAttributes {
+        let mut attributes = other.iter().fold(RichTextAttributes::new(), |mut attributes, (k, v)| {
+            if self.get(k) != Some(v) {
+                attributes.insert(k.clone(), v.clone());
+            }
+            attributes
+        });
+
+        self.keys().for_each(|k| {
+            if !other.contains_key(k) {
+                attributes.delete(k);
+            }
+        });
+        attributes
+    }
 }
 
 impl Attributes for RichTextAttributes {
@@ -118,11 +274,52 @@ impl Attributes for RichTextAttributes {
     }
 
     fn remove_empty(&mut self) {
-        self.inner.retain(|_, v| v.0.is_some());
+        self.inner.retain(|_, v| !v.is_null());
     }
 
     fn extend_other(&mut self, other: Self) {
-        self.inner.extend(other.inner);
+        self.inner.extend(other.inner.into_owned());
+    }
+
+    fn share(&mut self) {
+        if let AttributeMap::Owned(map) = &mut self.inner {
+            self.inner = AttributeMap::Shared(Arc::new(core::mem::take(map)));
+        }
+    }
+
+    fn transform_with_priority(&self, other: &Self, priority: bool) -> Self {
+        if !priority {
+            return other.clone();
+        }
+        other
+            .iter()
+            .filter(|(k, _)| !self.contains_key(k))
+            .fold(RichTextAttributes::new(), |mut attributes, (k, v)| {
+                attributes.insert(k.clone(), v.clone());
+                attributes
+            })
+    }
+
+    fn split_block(&self) -> (Self, Self) {
+        let (block, rest) = self
+            .inner
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .partition(|(k, _)| k.scope() == AttributeScope::Block);
+        (
+            RichTextAttributes {
+                inner: AttributeMap::Owned(block),
+            },
+            RichTextAttributes {
+                inner: AttributeMap::Owned(rest),
+            },
+        )
+    }
+
+    fn gravity(&self) -> Option<Gravity> {
+        self.get(&RichTextAttributeKey::Gravity)
+            .and_then(|value| value.as_str())
+            .and_then(|s| s.parse().ok())
     }
 }
 
@@ -180,20 +377,49 @@ impl OperationTransformable for RichTextAttributes {
     }
 }
 
-impl std::ops::Deref for RichTextAttributes {
-    type Target = HashMap<RichTextAttributeKey, RichTextAttributeValue>;
+// The BTreeMap iterates in the order of the keys, so the equal attributes hash their entries in
+// the same order.
+impl Hash for RichTextAttributes {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.inner.len());
+        self.inner.iter().for_each(|entry| entry.hash(state));
+    }
+}
+
+impl core::ops::Deref for RichTextAttributes {
+    type Target = BTreeMap<RichTextAttributeKey, RichTextAttributeValue>;
 
     fn deref(&self) -> &Self::Target {
         &self.inner
     }
 }
 
-impl std::ops::DerefMut for RichTextAttributes {
+impl core::ops::DerefMut for RichTextAttributes {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.inner
     }
 }
 
+/// Collects the key/value pairs into the attributes, the later value wins if a key repeats.
+///
+/// ```
+/// use lib_ot::rich_text::{RichTextAttributeKey, RichTextAttributeValue, RichTextAttributes};
+/// use std::iter::FromIterator;
+///
+/// let attributes = RichTextAttributes::from_iter(vec![
+///     (RichTextAttributeKey::Bold, RichTextAttributeValue::from(true)),
+///     (RichTextAttributeKey::Header, RichTextAttributeValue::from(1_usize)),
+/// ]);
+/// assert_eq!(attributes.len(), 2);
+/// ```
+impl FromIterator<(RichTextAttributeKey, RichTextAttributeValue)> for RichTextAttributes {
+    fn from_iter<I: IntoIterator<Item = (RichTextAttributeKey, RichTextAttributeValue)>>(iter: I) -> Self {
+        RichTextAttributes {
+            inner: AttributeMap::Owned(BTreeMap::from_iter(iter)),
+        }
+    }
+}
+
 pub fn attributes_except_header(op: &RichTextOperation) -> RichTextAttributes {
     let mut attributes = op.get_attributes();
     attributes.remove(RichTextAttributeKey::Header);
@@ -227,10 +453,12 @@ impl RichTextAttribute {
     block_attribute!(List, &str);
     block_attribute!(CodeBlock, bool);
     block_attribute!(BlockQuote, bool);
+    block_attribute!(Direction, &str);
 
     // ignore
     ignore_attribute!(Width, usize);
     ignore_attribute!(Height, usize);
+    ignore_attribute!(Gravity, Gravity);
 
     // List extension
     list_attribute!(Bullet, "bullet");
@@ -251,12 +479,12 @@ impl RichTextAttribute {
 
 impl fmt::Display for RichTextAttribute {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let s = format!("{:?}:{:?} {:?}", self.key, self.value.0, self.scope);
+        let s = format!("{:?}:{:?} {:?}", self.key, self.value, self.scope);
         f.write_str(&s)
     }
 }
 
-impl std::convert::From<RichTextAttribute> for RichTextAttributes {
+impl core::convert::From<RichTextAttribute> for RichTextAttributes {
     fn from(attr: RichTextAttribute) -> Self {
         let mut attributes = RichTextAttributes::new();
         attributes.add(attr);
@@ -264,97 +492,284 @@ impl std::convert::From<RichTextAttribute> for RichTextAttributes {
     }
 }
 
-#[derive(Clone, Debug, Display, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
-// serde.rs/variant-attrs.html
-// #[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Display, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub enum RichTextAttributeKey {
-    #[serde(rename = "bold")]
     Bold,
-    #[serde(rename = "italic")]
     Italic,
-    #[serde(rename = "underline")]
     Underline,
-    #[serde(rename = "strike")]
     StrikeThrough,
-    #[serde(rename = "font")]
     Font,
-    #[serde(rename = "size")]
     Size,
-    #[serde(rename = "link")]
     Link,
-    #[serde(rename = "color")]
     Color,
-    #[serde(rename = "background")]
     Background,
-    #[serde(rename = "indent")]
     Indent,
-    #[serde(rename = "align")]
     Align,
-    #[serde(rename = "code_block")]
     CodeBlock,
-    #[serde(rename = "code")]
     InlineCode,
-    #[serde(rename = "list")]
     List,
-    #[serde(rename = "blockquote")]
     BlockQuote,
-    #[serde(rename = "width")]
     Width,
-    #[serde(rename = "height")]
     Height,
-    #[serde(rename = "header")]
     Header,
+    Gravity,
+    Direction,
+    /// The key registered by `RichTextAttributeKey::register`, e.g. the mention of a plugin, or
+    /// the unknown key that is deserialized or imported by `RichTextDelta::from_quill_ops`.
+    Custom(String),
+}
+
+impl RichTextAttributeKey {
+    /// Returns whether the attribute of the key applies to the text, to the line or to neither,
+    /// e.g. the size of the image. The custom keys have the scope they are registered with.
+    pub fn scope(&self) -> AttributeScope {
+        match self {
+            RichTextAttributeKey::Header
+            | RichTextAttributeKey::Indent
+            | RichTextAttributeKey::Align
+            | RichTextAttributeKey::CodeBlock
+            | RichTextAttributeKey::List
+            | RichTextAttributeKey::BlockQuote
+            | RichTextAttributeKey::Direction => AttributeScope::Block,
+            RichTextAttributeKey::Bold
+            | RichTextAttributeKey::Italic
+            | RichTextAttributeKey::Underline
+            | RichTextAttributeKey::StrikeThrough
+            | RichTextAttributeKey::Link
+            | RichTextAttributeKey::Color
+            | RichTextAttributeKey::Font
+            | RichTextAttributeKey::Size
+            | RichTextAttributeKey::Background
+            | RichTextAttributeKey::InlineCode => AttributeScope::Inline,
+            RichTextAttributeKey::Width | RichTextAttributeKey::Height | RichTextAttributeKey::Gravity => {
+                AttributeScope::Ignore
+            }
+            RichTextAttributeKey::Custom(name) => custom_key_scope(name).unwrap_or(AttributeScope::Ignore),
+        }
+    }
+
+    /// Registers the custom key of the `name` with the `scope` of its attribute, so the attribute
+    /// is routed like the built-in ones, e.g. the block attribute is stored on the newline. The
+    /// unregistered key is deserialized with the `Ignore` scope, and registering it again replaces
+    /// its scope. Returns
+    /// the `InvalidOperation` error if the `name` is taken by the built-in key. The registry needs
+    /// the std feature.
+    #[cfg(feature = "std")]
+    pub fn register(name: &str, scope: AttributeScope) -> Result<RichTextAttributeKey, OTError> {
+        if RichTextAttributeKey::built_in(name).is_some() {
+            return Err(ErrorBuilder::new(OTErrorCode::InvalidOperation)
+                .msg(format!("The built-in key {} can't be registered", name))
+                .build());
+        }
+        CUSTOM_KEYS
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(name.to_owned(), scope);
+        Ok(RichTextAttributeKey::Custom(name.to_owned()))
+    }
+
+    /// Returns the key of the `name`, either the built-in key or the registered custom key.
+    pub fn from_name(name: &str) -> Option<RichTextAttributeKey> {
+        RichTextAttributeKey::built_in(name).or_else(|| {
+            custom_key_scope(name)
+                .is_some()
+                .then(|| RichTextAttributeKey::Custom(name.to_owned()))
+        })
+    }
+
+    /// Returns the name of the key in the JSON, e.g. `strike`.
+    pub fn name(&self) -> &str {
+        match self {
+            RichTextAttributeKey::Bold => "bold",
+            RichTextAttributeKey::Italic => "italic",
+            RichTextAttributeKey::Underline => "underline",
+            RichTextAttributeKey::StrikeThrough => "strike",
+            RichTextAttributeKey::Font => "font",
+            RichTextAttributeKey::Size => "size",
+            RichTextAttributeKey::Link => "link",
+            RichTextAttributeKey::Color => "color",
+            RichTextAttributeKey::Background => "background",
+            RichTextAttributeKey::Indent => "indent",
+            RichTextAttributeKey::Align => "align",
+            RichTextAttributeKey::CodeBlock => "code_block",
+            RichTextAttributeKey::InlineCode => "code",
+            RichTextAttributeKey::List => "list",
+            RichTextAttributeKey::BlockQuote => "blockquote",
+            RichTextAttributeKey::Width => "width",
+            RichTextAttributeKey::Height => "height",
+            RichTextAttributeKey::Header => "header",
+            RichTextAttributeKey::Gravity => "gravity",
+            RichTextAttributeKey::Direction => "direction",
+            RichTextAttributeKey::Custom(name) => name,
+        }
+    }
+
+    fn built_in(name: &str) -> Option<RichTextAttributeKey> {
+        let key = match name {
+            "bold" => RichTextAttributeKey::Bold,
+            "italic" => RichTextAttributeKey::Italic,
+            "underline" => RichTextAttributeKey::Underline,
+            "strike" => RichTextAttributeKey::StrikeThrough,
+            "font" => RichTextAttributeKey::Font,
+            "size" => RichTextAttributeKey::Size,
+            "link" => RichTextAttributeKey::Link,
+            "color" => RichTextAttributeKey::Color,
+            "background" => RichTextAttributeKey::Background,
+            "indent" => RichTextAttributeKey::Indent,
+            "align" => RichTextAttributeKey::Align,
+            "code_block" => RichTextAttributeKey::CodeBlock,
+            "code" => RichTextAttributeKey::InlineCode,
+            "list" => RichTextAttributeKey::List,
+            "blockquote" => RichTextAttributeKey::BlockQuote,
+            "width" => RichTextAttributeKey::Width,
+            "height" => RichTextAttributeKey::Height,
+            "header" => RichTextAttributeKey::Header,
+            "gravity" => RichTextAttributeKey::Gravity,
+            "direction" => RichTextAttributeKey::Direction,
+            _ => return None,
+        };
+        Some(key)
+    }
+}
+
+#[cfg(feature = "std")]
+fn custom_key_scope(name: &str) -> Option<AttributeScope> {
+    CUSTOM_KEYS.read().unwrap_or_else(|e| e.into_inner()).get(name).cloned()
+}
+
+#[cfg(not(feature = "std"))]
+fn custom_key_scope(_name: &str) -> Option<AttributeScope> {
+    None
 }
 
 // pub trait AttributeValueData<'a>: Serialize + Deserialize<'a> {}
+/// The value of the attribute. `Null` marks the attribute as removed when composing, the other
+/// values keep their JSON type, so `Bool(false)` is a value rather than the removal.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RichTextAttributeValue(pub Option<String>);
+pub enum RichTextAttributeValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Str(String),
+}
+
+impl RichTextAttributeValue {
+    /// Returns the value of the toggle attribute, e.g. `RichTextAttribute::Bold(false)`, which
+    /// removes the attribute instead of setting it to `false`.
+    pub fn toggle(val: bool) -> Self {
+        match val {
+            true => RichTextAttributeValue::Bool(true),
+            false => RichTextAttributeValue::Null,
+        }
+    }
+
+    /// The null value marks the attribute as removed when composing.
+    pub fn is_null(&self) -> bool {
+        matches!(self, RichTextAttributeValue::Null)
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            RichTextAttributeValue::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            RichTextAttributeValue::Bool(b) => Some(*b),
+            RichTextAttributeValue::Str(s) => s.parse::<bool>().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_usize(&self) -> Option<usize> {
+        match self {
+            RichTextAttributeValue::Int(n) => usize::try_from(*n).ok(),
+            RichTextAttributeValue::Str(s) => s.parse::<usize>().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl RichTextAttributeValue {
+    // Reads the string of the older JSON, e.g. `{"header": "1"}`, as the type of its key. The font
+    // and the size stay strings, the clients send the names like `"monospace"` or `"large"`.
+    pub(crate) fn typed_for(self, key: &RichTextAttributeKey) -> Self {
+        let s = match &self {
+            RichTextAttributeValue::Str(s) => s,
+            _ => return self,
+        };
+        match key {
+            RichTextAttributeKey::Bold
+            | RichTextAttributeKey::Italic
+            | RichTextAttributeKey::Underline
+            | RichTextAttributeKey::StrikeThrough
+            | RichTextAttributeKey::CodeBlock
+            | RichTextAttributeKey::InlineCode
+            | RichTextAttributeKey::BlockQuote => s.parse().map(RichTextAttributeValue::Bool).unwrap_or(self),
+            RichTextAttributeKey::Header
+            | RichTextAttributeKey::Indent
+            | RichTextAttributeKey::Width
+            | RichTextAttributeKey::Height => s.parse().map(RichTextAttributeValue::Int).unwrap_or(self),
+            _ => self,
+        }
+    }
+}
 
-impl std::convert::From<&usize> for RichTextAttributeValue {
+impl core::convert::From<&usize> for RichTextAttributeValue {
     fn from(val: &usize) -> Self {
         RichTextAttributeValue::from(*val)
     }
 }
 
-impl std::convert::From<usize> for RichTextAttributeValue {
+impl core::convert::From<usize> for RichTextAttributeValue {
     fn from(val: usize) -> Self {
-        if val > 0_usize {
-            RichTextAttributeValue(Some(format!("{}", val)))
-        } else {
-            RichTextAttributeValue(None)
+        match i64::try_from(val) {
+            Ok(val) if val > 0 => RichTextAttributeValue::Int(val),
+            Ok(_) => RichTextAttributeValue::Null,
+            Err(_) => RichTextAttributeValue::Str(val.to_string()),
         }
     }
 }
 
-impl std::convert::From<&str> for RichTextAttributeValue {
+impl core::convert::From<i64> for RichTextAttributeValue {
+    fn from(val: i64) -> Self {
+        RichTextAttributeValue::Int(val)
+    }
+}
+
+impl core::convert::From<&str> for RichTextAttributeValue {
     fn from(val: &str) -> Self {
         val.to_owned().into()
     }
 }
 
-impl std::convert::From<String> for RichTextAttributeValue {
+impl core::convert::From<String> for RichTextAttributeValue {
     fn from(val: String) -> Self {
         if val.is_empty() {
-            RichTextAttributeValue(None)
+            RichTextAttributeValue::Null
         } else {
-            RichTextAttributeValue(Some(val))
+            RichTextAttributeValue::Str(val)
         }
     }
 }
 
-impl std::convert::From<&bool> for RichTextAttributeValue {
+impl core::convert::From<&bool> for RichTextAttributeValue {
     fn from(val: &bool) -> Self {
         RichTextAttributeValue::from(*val)
     }
 }
 
-impl std::convert::From<bool> for RichTextAttributeValue {
+impl core::convert::From<Gravity> for RichTextAttributeValue {
+    fn from(gravity: Gravity) -> Self {
+        RichTextAttributeValue::Str(gravity.as_str().to_owned())
+    }
+}
+
+impl core::convert::From<bool> for RichTextAttributeValue {
     fn from(val: bool) -> Self {
-        let val = match val {
-            true => Some("true".to_owned()),
-            false => None,
-        };
-        RichTextAttributeValue(val)
+        RichTextAttributeValue::Bool(val)
     }
 }
 
@@ -362,32 +777,12 @@ pub fn is_block_except_header(k: &RichTextAttributeKey) -> bool {
     if k == &RichTextAttributeKey::Header {
         return false;
     }
-    BLOCK_KEYS.contains(k)
+    k.scope() == AttributeScope::Block
 }
 
+#[cfg(feature = "std")]
 lazy_static! {
-    static ref BLOCK_KEYS: HashSet<RichTextAttributeKey> = HashSet::from_iter(vec![
-        RichTextAttributeKey::Header,
-        RichTextAttributeKey::Indent,
-        RichTextAttributeKey::Align,
-        RichTextAttributeKey::CodeBlock,
-        RichTextAttributeKey::List,
-        RichTextAttributeKey::BlockQuote,
-    ]);
-    static ref INLINE_KEYS: HashSet<RichTextAttributeKey> = HashSet::from_iter(vec![
-        RichTextAttributeKey::Bold,
-        RichTextAttributeKey::Italic,
-        RichTextAttributeKey::Underline,
-        RichTextAttributeKey::StrikeThrough,
-        RichTextAttributeKey::Link,
-        RichTextAttributeKey::Color,
-        RichTextAttributeKey::Font,
-        RichTextAttributeKey::Size,
-        RichTextAttributeKey::Background,
-        RichTextAttributeKey::InlineCode,
-    ]);
-    static ref INGORE_KEYS: HashSet<RichTextAttributeKey> =
-        HashSet::from_iter(vec![RichTextAttributeKey::Width, RichTextAttributeKey::Height,]);
+    static ref CUSTOM_KEYS: RwLock<HashMap<String, AttributeScope>> = RwLock::new(HashMap::new());
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
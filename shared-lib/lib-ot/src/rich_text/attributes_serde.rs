@@ -1,12 +1,13 @@
 #[rustfmt::skip]
 use crate::rich_text::{RichTextAttribute, RichTextAttributeKey, RichTextAttributes, RichTextAttributeValue};
+use alloc::{borrow::ToOwned, string::ToString};
+use core::{convert::TryFrom, fmt};
 use serde::{
     de,
     de::{MapAccess, Visitor},
     ser::SerializeMap,
     Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::fmt;
 
 impl Serialize for RichTextAttribute {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
@@ -29,7 +30,7 @@ impl Serialize for RichTextAttributes {
         }
 
         let mut map = serializer.serialize_map(Some(self.inner.len()))?;
-        for (k, v) in &self.inner {
+        for (k, v) in self.inner.iter() {
             let _ = serial_attribute(&mut map, k, v)?;
         }
         map.end()
@@ -45,39 +46,11 @@ where
     S: SerializeMap,
     E: From<<S as SerializeMap>::Error>,
 {
-    if let Some(v) = &value.0 {
-        match key {
-            RichTextAttributeKey::Bold
-            | RichTextAttributeKey::Italic
-            | RichTextAttributeKey::Underline
-            | RichTextAttributeKey::StrikeThrough
-            | RichTextAttributeKey::CodeBlock
-            | RichTextAttributeKey::InlineCode
-            | RichTextAttributeKey::BlockQuote => match &v.parse::<bool>() {
-                Ok(value) => map_serializer.serialize_entry(&key, value)?,
-                Err(e) => log::error!("Serial {:?} failed. {:?}", &key, e),
-            },
-
-            RichTextAttributeKey::Font
-            | RichTextAttributeKey::Size
-            | RichTextAttributeKey::Header
-            | RichTextAttributeKey::Indent
-            | RichTextAttributeKey::Width
-            | RichTextAttributeKey::Height => match &v.parse::<i32>() {
-                Ok(value) => map_serializer.serialize_entry(&key, value)?,
-                Err(e) => log::error!("Serial {:?} failed. {:?}", &key, e),
-            },
-
-            RichTextAttributeKey::Link
-            | RichTextAttributeKey::Color
-            | RichTextAttributeKey::Background
-            | RichTextAttributeKey::Align
-            | RichTextAttributeKey::List => {
-                map_serializer.serialize_entry(&key, v)?;
-            }
-        }
-    } else {
-        map_serializer.serialize_entry(&key, "")?;
+    match value {
+        RichTextAttributeValue::Null => map_serializer.serialize_entry(&key, "")?,
+        RichTextAttributeValue::Bool(b) => map_serializer.serialize_entry(&key, b)?,
+        RichTextAttributeValue::Int(n) => map_serializer.serialize_entry(&key, n)?,
+        RichTextAttributeValue::Str(s) => map_serializer.serialize_entry(&key, s)?,
     }
     Ok(())
 }
@@ -100,7 +73,7 @@ impl<'de> Deserialize<'de> for RichTextAttributes {
             {
                 let mut attributes = RichTextAttributes::new();
                 while let Some(key) = map.next_key::<RichTextAttributeKey>()? {
-                    let value = map.next_value::<RichTextAttributeValue>()?;
+                    let value = map.next_value::<RichTextAttributeValue>()?.typed_for(&key);
                     attributes.insert(key, value);
                 }
 
@@ -111,14 +84,51 @@ impl<'de> Deserialize<'de> for RichTextAttributes {
     }
 }
 
+impl Serialize for RichTextAttributeKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for RichTextAttributeKey {
+    fn deserialize<D>(deserializer: D) -> Result<RichTextAttributeKey, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AttributeKeyVisitor;
+        impl<'de> Visitor<'de> for AttributeKeyVisitor {
+            type Value = RichTextAttributeKey;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("the attribute key")
+            }
+
+            // The unregistered key is kept as the custom key with the `Ignore` scope, so the
+            // content written by the newer versions or the plugins survives the round-trip.
+            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(RichTextAttributeKey::from_name(s).unwrap_or_else(|| RichTextAttributeKey::Custom(s.to_owned())))
+            }
+        }
+
+        deserializer.deserialize_str(AttributeKeyVisitor)
+    }
+}
+
 impl Serialize for RichTextAttributeValue {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        match &self.0 {
-            None => serializer.serialize_none(),
-            Some(val) => serializer.serialize_str(val),
+        match self {
+            RichTextAttributeValue::Null => serializer.serialize_none(),
+            RichTextAttributeValue::Bool(b) => serializer.serialize_bool(*b),
+            RichTextAttributeValue::Int(n) => serializer.serialize_i64(*n),
+            RichTextAttributeValue::Str(s) => serializer.serialize_str(s),
         }
     }
 }
@@ -145,56 +155,59 @@ impl<'de> Deserialize<'de> for RichTextAttributeValue {
             where
                 E: de::Error,
             {
-                Ok(RichTextAttributeValue(Some(format!("{}", value))))
+                Ok(RichTextAttributeValue::Int(i64::from(value)))
             }
 
             fn visit_i16<E>(self, value: i16) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(RichTextAttributeValue(Some(format!("{}", value))))
+                Ok(RichTextAttributeValue::Int(i64::from(value)))
             }
 
             fn visit_i32<E>(self, value: i32) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(RichTextAttributeValue(Some(format!("{}", value))))
+                Ok(RichTextAttributeValue::Int(i64::from(value)))
             }
 
             fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(RichTextAttributeValue(Some(format!("{}", value))))
+                Ok(RichTextAttributeValue::Int(value))
             }
 
             fn visit_u8<E>(self, value: u8) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(RichTextAttributeValue(Some(format!("{}", value))))
+                Ok(RichTextAttributeValue::Int(i64::from(value)))
             }
 
             fn visit_u16<E>(self, value: u16) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(RichTextAttributeValue(Some(format!("{}", value))))
+                Ok(RichTextAttributeValue::Int(i64::from(value)))
             }
 
             fn visit_u32<E>(self, value: u32) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(RichTextAttributeValue(Some(format!("{}", value))))
+                Ok(RichTextAttributeValue::Int(i64::from(value)))
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(RichTextAttributeValue(Some(format!("{}", value))))
+                match i64::try_from(value) {
+                    Ok(value) => Ok(RichTextAttributeValue::Int(value)),
+                    Err(_) => Ok(RichTextAttributeValue::Str(value.to_string())),
+                }
             }
 
             fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
@@ -208,7 +221,7 @@ impl<'de> Deserialize<'de> for RichTextAttributeValue {
             where
                 E: de::Error,
             {
-                Ok(RichTextAttributeValue(None))
+                Ok(RichTextAttributeValue::Null)
             }
 
             fn visit_unit<E>(self) -> Result<Self::Value, E>
@@ -216,7 +229,7 @@ impl<'de> Deserialize<'de> for RichTextAttributeValue {
                 E: de::Error,
             {
                 // the value that contains null will be processed here.
-                Ok(RichTextAttributeValue(None))
+                Ok(RichTextAttributeValue::Null)
             }
 
             fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
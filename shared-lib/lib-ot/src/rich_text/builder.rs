@@ -6,7 +6,7 @@ pub struct AttributeBuilder {
     inner: RichTextAttributes,
 }
 
-impl std::default::Default for AttributeBuilder {
+impl core::default::Default for AttributeBuilder {
     fn default() -> Self {
         Self {
             inner: RichTextAttributes::default(),
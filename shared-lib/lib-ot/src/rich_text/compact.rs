@@ -0,0 +1,262 @@
+use crate::{
+    core::{Embed, Operation},
+    errors::{ErrorBuilder, OTError, OTErrorCode},
+    rich_text::{RichTextAttributeKey, RichTextAttributeValue, RichTextAttributes, RichTextDelta},
+};
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{convert::TryFrom, str};
+
+const RETAIN_TAG: u8 = 0;
+const INSERT_TAG: u8 = 1;
+const EMBED_TAG: u8 = 2;
+const DELETE_TAG: u8 = 3;
+
+const NULL_VALUE_TAG: u8 = 0;
+const STRING_VALUE_TAG: u8 = 1;
+const TRUE_VALUE_TAG: u8 = 2;
+const FALSE_VALUE_TAG: u8 = 3;
+const INT_VALUE_TAG: u8 = 4;
+
+impl RichTextDelta {
+    /// Encodes the delta to the compact binary format, which is much smaller than the JSON for
+    /// the network sync. The lengths are written as varints and every operation starts with a tag
+    /// byte. The attribute keys are interned: every key used by the delta is written once in the
+    /// key table at the beginning, and the attributes refer to the keys by their indexes.
+    ///
+    /// The layout is:
+    /// * key table: the number of keys, then every key as a string.
+    /// * operations: the number of operations, then every operation as the tag followed by
+    ///   the length of the retain or delete, the string of the insert or the type and the JSON
+    ///   value of the embed. The retain, insert and embed end with their attributes.
+    /// * attributes: the number of entries, then every entry as the key index followed by the
+    ///   value, which is the null, true or false tag, the string tag with the string or the int tag
+    ///   with the zigzag varint.
+    ///
+    /// The string is written as its length in bytes followed by the UTF-8 bytes. The varints are
+    /// 64 bits wide, so the bytes decode the same on the 32-bit and the 64-bit targets.
+    pub fn to_compact_bytes(&self) -> Vec<u8> {
+        let mut keys: Vec<&RichTextAttributeKey> = vec![];
+        for attributes in self.ops.iter().filter_map(|op| op.attributes_ref()) {
+            for key in attributes.keys() {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+
+        let mut bytes = vec![];
+        write_len(&mut bytes, keys.len());
+        for key in &keys {
+            write_str(&mut bytes, &key_name(key));
+        }
+
+        write_len(&mut bytes, self.ops.len());
+        for op in &self.ops {
+            match op {
+                Operation::Retain(retain) => {
+                    bytes.push(RETAIN_TAG);
+                    write_len(&mut bytes, retain.n);
+                    write_attributes(&mut bytes, &keys, &retain.attributes);
+                }
+                Operation::Insert(insert) => {
+                    match &insert.embed {
+                        None => {
+                            bytes.push(INSERT_TAG);
+                            write_str(&mut bytes, &insert.s);
+                        }
+                        Some(embed) => {
+                            bytes.push(EMBED_TAG);
+                            write_str(&mut bytes, &embed.ty);
+                            write_str(&mut bytes, &embed.value.to_string());
+                        }
+                    }
+                    write_attributes(&mut bytes, &keys, &insert.attributes);
+                }
+                Operation::Delete(n) => {
+                    bytes.push(DELETE_TAG);
+                    write_len(&mut bytes, *n);
+                }
+            }
+        }
+        bytes
+    }
+
+    /// Decodes the delta encoded by `to_compact_bytes`. Returns the `SerdeError` error if the
+    /// bytes are truncated, have trailing bytes or contain an unknown tag. The unknown keys are
+    /// kept as the custom keys like `from_delta_str` does.
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self, OTError> {
+        let mut reader = CompactReader { bytes, offset: 0 };
+        let mut keys = vec![];
+        for _ in 0..reader.read_len()? {
+            let name = reader.read_str()?;
+            keys.push(key_from_name(name)?);
+        }
+
+        let mut delta = RichTextDelta::new();
+        for _ in 0..reader.read_len()? {
+            match reader.read_u8()? {
+                RETAIN_TAG => {
+                    let n = reader.read_len()?;
+                    let attributes = reader.read_attributes(&keys)?;
+                    delta.retain(n, attributes);
+                }
+                INSERT_TAG => {
+                    let s = reader.read_str()?;
+                    let attributes = reader.read_attributes(&keys)?;
+                    delta.insert(s, attributes);
+                }
+                EMBED_TAG => {
+                    let ty = reader.read_str()?;
+                    let value = serde_json::from_str::<serde_json::Value>(reader.read_str()?)
+                        .map_err(|e| compact_error(format!("Invalid embed value: {}", e)))?;
+                    let attributes = reader.read_attributes(&keys)?;
+                    delta.insert_embed(Embed::new(ty, value), attributes);
+                }
+                DELETE_TAG => delta.delete(reader.read_len()?),
+                tag => return Err(compact_error(format!("Unknown operation tag: {}", tag))),
+            }
+        }
+
+        if reader.offset != bytes.len() {
+            return Err(compact_error(format!(
+                "{} trailing bytes after the delta",
+                bytes.len() - reader.offset
+            )));
+        }
+        Ok(delta)
+    }
+}
+
+fn compact_error<T: Into<String>>(msg: T) -> OTError {
+    ErrorBuilder::new(OTErrorCode::SerdeError).msg(msg).build()
+}
+
+// The keys are written by their serde names, e.g. `strike`, which are stable across versions.
+fn key_name(key: &RichTextAttributeKey) -> String {
+    match serde_json::to_value(key) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => key.to_string(),
+    }
+}
+
+fn key_from_name(name: &str) -> Result<RichTextAttributeKey, OTError> {
+    serde_json::from_value(serde_json::Value::String(name.to_owned()))
+        .map_err(|_| compact_error(format!("Unknown attribute key: {}", name)))
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        bytes.push((n as u8 & 0x7f) | 0x80);
+        n >>= 7;
+    }
+    bytes.push(n as u8);
+}
+
+fn write_len(bytes: &mut Vec<u8>, len: usize) {
+    write_varint(bytes, len as u64);
+}
+
+fn write_str(bytes: &mut Vec<u8>, s: &str) {
+    write_len(bytes, s.len());
+    bytes.extend_from_slice(s.as_bytes());
+}
+
+fn write_attributes(bytes: &mut Vec<u8>, keys: &[&RichTextAttributeKey], attributes: &RichTextAttributes) {
+    write_len(bytes, attributes.len());
+    for (key, value) in attributes.iter() {
+        let index = keys.iter().position(|k| *k == key).unwrap_or_default();
+        write_len(bytes, index);
+        match value {
+            RichTextAttributeValue::Null => bytes.push(NULL_VALUE_TAG),
+            RichTextAttributeValue::Bool(true) => bytes.push(TRUE_VALUE_TAG),
+            RichTextAttributeValue::Bool(false) => bytes.push(FALSE_VALUE_TAG),
+            RichTextAttributeValue::Int(n) => {
+                bytes.push(INT_VALUE_TAG);
+                write_varint(bytes, ((n << 1) ^ (n >> 63)) as u64);
+            }
+            RichTextAttributeValue::Str(s) => {
+                bytes.push(STRING_VALUE_TAG);
+                write_str(bytes, s);
+            }
+        }
+    }
+}
+
+struct CompactReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> CompactReader<'a> {
+    fn read_u8(&mut self) -> Result<u8, OTError> {
+        let byte = *self
+            .bytes
+            .get(self.offset)
+            .ok_or_else(|| compact_error("Unexpected end of the bytes"))?;
+        self.offset += 1;
+        Ok(byte)
+    }
+
+    // The tenth byte holds the 64th bit only, any higher bit overflows the u64.
+    fn read_varint(&mut self) -> Result<u64, OTError> {
+        let mut n: u64 = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            if shift == 63 && byte > 1 {
+                return Err(compact_error("The varint overflows"));
+            }
+            n |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(n);
+            }
+            shift += 7;
+        }
+    }
+
+    fn read_len(&mut self) -> Result<usize, OTError> {
+        let n = self.read_varint()?;
+        usize::try_from(n).map_err(|_| compact_error(format!("The length {} overflows the usize", n)))
+    }
+
+    fn read_str(&mut self) -> Result<&'a str, OTError> {
+        let len = self.read_len()?;
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| compact_error("Unexpected end of the bytes"))?;
+        let s = str::from_utf8(&self.bytes[self.offset..end])?;
+        self.offset = end;
+        Ok(s)
+    }
+
+    fn read_attributes(&mut self, keys: &[RichTextAttributeKey]) -> Result<RichTextAttributes, OTError> {
+        let mut attributes = RichTextAttributes::new();
+        for _ in 0..self.read_len()? {
+            let index = self.read_len()?;
+            let key = keys
+                .get(index)
+                .ok_or_else(|| compact_error(format!("Unknown attribute key index: {}", index)))?;
+            let value = match self.read_u8()? {
+                NULL_VALUE_TAG => RichTextAttributeValue::Null,
+                STRING_VALUE_TAG => RichTextAttributeValue::Str(self.read_str()?.to_owned()),
+                TRUE_VALUE_TAG => RichTextAttributeValue::Bool(true),
+                FALSE_VALUE_TAG => RichTextAttributeValue::Bool(false),
+                INT_VALUE_TAG => {
+                    let n = self.read_varint()?;
+                    RichTextAttributeValue::Int((n >> 1) as i64 ^ -((n & 1) as i64))
+                }
+                tag => return Err(compact_error(format!("Unknown attribute value tag: {}", tag))),
+            };
+            attributes.insert(key.clone(), value);
+        }
+        Ok(attributes)
+    }
+}
@@ -1,7 +1,144 @@
 use crate::{
-    core::{Delta, DeltaBuilder},
-    rich_text::RichTextAttributes,
+    core::{Attributes, Delta, DeltaBuilder, DeltaIter, Interval, Operation},
+    errors::{ErrorBuilder, OTError, OTErrorCode},
+    rich_text::{markdown::attribute_str, RichTextAttributeKey, RichTextAttributes},
 };
+use alloc::format;
+use core::cmp::min;
 
 pub type RichTextDelta = Delta<RichTextAttributes>;
 pub type RichTextDeltaBuilder = DeltaBuilder<RichTextAttributes>;
+
+impl RichTextDelta {
+    /// Shrinks the delta that will be applied to the `base` document, e.g. before sending it.
+    ///
+    /// The attributes of every retain are reduced to the ones that really change the text they
+    /// format: the value that the text already has and the removal of the attribute that the text
+    /// doesn't have are dropped. So bolding and then unbolding the plain text, which composes into
+    /// the retain with `bold: null`, ends up as the plain retain. Then the adjacent retains with
+    /// the same attributes are merged. The result changes `base` the same way as the delta, but it
+    /// must not be applied to another document.
+    pub fn optimize(&mut self, base: &RichTextDelta) {
+        let mut optimized = RichTextDelta::with_capacity(self.ops.len());
+        let mut offset = 0;
+        for op in &self.ops {
+            match op {
+                Operation::Retain(retain) if !retain.attributes.is_empty() => {
+                    let mut len = 0;
+                    for base_op in DeltaIter::from_interval(base, Interval::new(offset, offset + retain.n)) {
+                        let base_attributes = base_op.get_attributes();
+                        let mut attributes = base_attributes.clone();
+                        attributes.extend_other(retain.attributes.clone());
+                        attributes.remove_empty();
+                        optimized.retain(base_op.len(), base_attributes.diff(&attributes));
+                        len += base_op.len();
+                    }
+                    // The part beyond the end of the base is kept as it is.
+                    optimized.retain(retain.n - len, retain.attributes.clone());
+                    offset += retain.n;
+                }
+                Operation::Retain(_) | Operation::Delete(_) => {
+                    offset += op.len();
+                    optimized.add(op.clone());
+                }
+                Operation::Insert(_) => optimized.add(op.clone()),
+            }
+        }
+        *self = optimized;
+    }
+
+    /// Returns the formatting-only delta that turns the document delta into `other`, which must
+    /// have the same content, e.g. to apply the formatting of a theme to the existing document.
+    /// The delta retains every range with the diff of the attributes of both sides, so
+    /// `self.compose(&diff)? == *other`. Returns the `ApplyFormatFail` error if the content
+    /// differs.
+    pub fn diff_attributes(&self, other: &RichTextDelta) -> Result<RichTextDelta, OTError> {
+        let mut diff = RichTextDelta::new();
+        let mut iter = DeltaIter::new(self);
+        let mut other_iter = DeltaIter::new(other);
+        let mut offset = 0;
+        while iter.has_next() || other_iter.has_next() {
+            let len = min(
+                iter.next_op_len().unwrap_or_default(),
+                other_iter.next_op_len().unwrap_or_default(),
+            );
+            match (iter.next_op_with_len(len), other_iter.next_op_with_len(len)) {
+                (Some(Operation::Insert(insert)), Some(Operation::Insert(other_insert)))
+                    if insert.s == other_insert.s && insert.embed == other_insert.embed =>
+                {
+                    diff.retain(len, insert.attributes.diff(&other_insert.attributes));
+                }
+                _ => {
+                    return Err(ErrorBuilder::new(OTErrorCode::ApplyFormatFail)
+                        .msg(format!("The content differs at {}", offset))
+                        .build())
+                }
+            }
+            offset += len;
+        }
+        Ok(diff)
+    }
+
+    /// Returns the direction of the line that the character at the `index` is on, e.g. `rtl`. Like
+    /// Quill, the direction is the block attribute of the newline that ends the line. `None` is the
+    /// default left-to-right direction, which is also returned for the index past the end.
+    ///
+    /// The crate doesn't do the bidi layout: the `index`, like all the offsets of the delta, is the
+    /// logical offset in UTF-16 code units, no matter in which direction the text is displayed.
+    pub fn direction_at(&self, index: usize) -> Option<&str> {
+        self.line_direction(index).flatten()
+    }
+
+    /// Returns true if the characters before and after the `index` are on the lines of different
+    /// directions, i.e. the index is at the boundary of two direction runs, where the editor applies
+    /// the bidi caret rules. The start and the end of the document aren't boundaries.
+    pub fn is_direction_boundary(&self, index: usize) -> bool {
+        if index == 0 {
+            return false;
+        }
+        match (self.line_direction(index - 1), self.line_direction(index)) {
+            (Some(before), Some(after)) => before != after,
+            _ => false,
+        }
+    }
+
+    // Returns `None` if there is no character at the `index`.
+    fn line_direction(&self, index: usize) -> Option<Option<&str>> {
+        let mut offset = 0;
+        let mut found = false;
+        for op in &self.ops {
+            let insert = match op {
+                Operation::Insert(insert) => insert,
+                _ => continue,
+            };
+            let len = insert.utf16_size();
+            let rest = if found {
+                insert.s.as_str()
+            } else if index < offset + len {
+                found = true;
+                // The text of the insert from the character at the index.
+                let mut end = offset;
+                match insert.s.char_indices().find(|(_, c)| {
+                    end += c.len_utf16();
+                    end > index
+                }) {
+                    Some((i, _)) => &insert.s[i..],
+                    None => "",
+                }
+            } else {
+                offset += len;
+                continue;
+            };
+
+            if rest.contains('\n') {
+                return Some(attribute_str(&insert.attributes, &RichTextAttributeKey::Direction));
+            }
+            offset += len;
+        }
+        if found {
+            Some(None)
+        } else {
+            None
+        }
+    }
+}
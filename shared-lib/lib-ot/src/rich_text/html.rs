@@ -0,0 +1,141 @@
+use crate::{
+    core::Operation,
+    rich_text::{
+        markdown::{attribute_bool, attribute_str, attribute_usize},
+        RichTextAttributeKey, RichTextAttributes, RichTextDelta,
+    },
+};
+use alloc::{borrow::ToOwned, format, string::String};
+
+impl RichTextDelta {
+    /// Renders the document delta to HTML.
+    ///
+    /// Every line is rendered by the block attributes stored on the newline that ends it: the
+    /// consecutive list items are grouped into `<ul>` or `<ol>`, the header becomes `<h1>`..`<h6>`,
+    /// the block quote becomes `<blockquote>` and the other lines become `<p>`. The `bold`, `italic`,
+    /// `code` and `link` attributes are rendered as `<strong>`, `<em>`, `<code>` and `<a>`. The
+    /// special characters of the text and the attribute values are escaped.
+    ///
+    /// Only the `http`, `https` and `mailto` links are kept, the other ones, e.g. `javascript:`,
+    /// are rendered as the plain text. The image also accepts the `data:image/` URL, and the image
+    /// with any other source is dropped.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        let mut line = String::new();
+        let mut list_tag = None;
+        for op in &self.ops {
+            let insert = match op {
+                Operation::Insert(insert) => insert,
+                _ => continue,
+            };
+
+            if let Some(embed) = &insert.embed {
+                match embed.value.as_str() {
+                    Some(src) if embed.ty == "image" && is_safe_url(src, true) => {
+                        line.push_str(&format!("<img src=\"{}\">", escape_html(src)))
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            for (i, text) in insert.s.split('\n').enumerate() {
+                if i > 0 {
+                    push_line(&mut html, &line, &insert.attributes, &mut list_tag);
+                    line.clear();
+                }
+                line.push_str(&inline_html(text, &insert.attributes));
+            }
+        }
+
+        if !line.is_empty() {
+            push_line(&mut html, &line, &RichTextAttributes::default(), &mut list_tag);
+        }
+        if let Some(tag) = list_tag {
+            html.push_str(&format!("</{}>", tag));
+        }
+        html
+    }
+}
+
+fn push_line(html: &mut String, line: &str, attributes: &RichTextAttributes, list_tag: &mut Option<&'static str>) {
+    let line = if line.is_empty() { "<br>" } else { line };
+    let new_list_tag = attribute_str(attributes, &RichTextAttributeKey::List).map(|list| match list {
+        "ordered" => "ol",
+        _ => "ul",
+    });
+    if *list_tag != new_list_tag {
+        if let Some(tag) = list_tag {
+            html.push_str(&format!("</{}>", tag));
+        }
+        if let Some(tag) = new_list_tag {
+            html.push_str(&format!("<{}>", tag));
+        }
+        *list_tag = new_list_tag;
+    }
+
+    if new_list_tag.is_some() {
+        html.push_str(&format!("<li>{}</li>", line));
+        return;
+    }
+
+    let header = attribute_usize(attributes, &RichTextAttributeKey::Header).filter(|level| (1..=6).contains(level));
+    let tag = match header {
+        Some(level) => format!("h{}", level),
+        None if attribute_bool(attributes, &RichTextAttributeKey::BlockQuote) => "blockquote".to_owned(),
+        None => "p".to_owned(),
+    };
+    html.push_str(&format!("<{}>{}</{}>", tag, line, tag));
+}
+
+fn inline_html(text: &str, attributes: &RichTextAttributes) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+
+    let mut html = escape_html(text);
+    if attribute_bool(attributes, &RichTextAttributeKey::InlineCode) {
+        html = format!("<code>{}</code>", html);
+    }
+    if attribute_bool(attributes, &RichTextAttributeKey::Italic) {
+        html = format!("<em>{}</em>", html);
+    }
+    if attribute_bool(attributes, &RichTextAttributeKey::Bold) {
+        html = format!("<strong>{}</strong>", html);
+    }
+    if let Some(link) = attribute_str(attributes, &RichTextAttributeKey::Link).filter(|link| is_safe_url(link, false)) {
+        html = format!("<a href=\"{}\">{}</a>", escape_html(link), html);
+    }
+    html
+}
+
+// The browsers ignore the leading whitespace and the tabs and newlines inside the URL, e.g.
+// `java\tscript:`, so they are removed before the scheme is checked.
+fn is_safe_url(url: &str, is_image: bool) -> bool {
+    let url = url
+        .trim_start_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .take(16)
+        .collect::<String>()
+        .to_ascii_lowercase();
+    ["http://", "https://", "mailto:"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme))
+        || (is_image && url.starts_with("data:image/"))
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
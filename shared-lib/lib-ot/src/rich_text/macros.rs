@@ -1,5 +1,17 @@
 #[macro_export]
 macro_rules! inline_attribute {
+    (
+        $key: ident,
+        bool
+    ) => {
+        pub fn $key(value: bool) -> Self {
+            Self {
+                key: RichTextAttributeKey::$key,
+                value: RichTextAttributeValue::toggle(value),
+                scope: AttributeScope::Inline,
+            }
+        }
+    };
     (
         $key: ident,
         $value: ty
@@ -16,6 +28,18 @@ macro_rules! inline_attribute {
 
 #[macro_export]
 macro_rules! block_attribute {
+    (
+        $key: ident,
+        bool
+    ) => {
+        pub fn $key(value: bool) -> Self {
+            Self {
+                key: RichTextAttributeKey::$key,
+                value: RichTextAttributeValue::toggle(value),
+                scope: AttributeScope::Block,
+            }
+        }
+    };
     (
         $key: ident,
         $value: ty
@@ -61,3 +85,32 @@ macro_rules! ignore_attribute {
         }
     };
 }
+
+/// Builds the `RichTextAttributes` from the `RichTextAttribute` constructors, so every value is
+/// checked against the type of its attribute, e.g. `Header` takes a `usize`. `Bold: false` is the
+/// removal of the bold like `RichTextAttribute::Bold(false)`, and no attributes expand to the empty
+/// attributes.
+///
+/// ```
+/// use lib_ot::{attributes, rich_text::{RichTextAttribute, RichTextAttributes}};
+///
+/// let attributes = attributes! { Bold: true, Header: 1 };
+/// let mut expected = RichTextAttributes::new();
+/// expected.add(RichTextAttribute::Bold(true));
+/// expected.add(RichTextAttribute::Header(1));
+/// assert_eq!(attributes, expected);
+///
+/// let attributes: RichTextAttributes = attributes! {};
+/// assert!(attributes.is_empty());
+/// ```
+#[macro_export]
+macro_rules! attributes {
+    () => {
+        $crate::rich_text::RichTextAttributes::new()
+    };
+    ($($key: ident: $value: expr),+ $(,)?) => {{
+        let mut attributes = $crate::rich_text::RichTextAttributes::new();
+        $(attributes.add($crate::rich_text::RichTextAttribute::$key($value));)+
+        attributes
+    }};
+}
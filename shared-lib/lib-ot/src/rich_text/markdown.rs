@@ -0,0 +1,363 @@
+use crate::{
+    core::Operation,
+    rich_text::{RichTextAttribute, RichTextAttributeKey, RichTextAttributes, RichTextDelta},
+};
+use alloc::{borrow::Cow, borrow::ToOwned, format, string::String, vec, vec::Vec};
+
+impl RichTextDelta {
+    /// Renders the document delta to Markdown.
+    ///
+    /// The `bold`, `italic`, `code` and `link` attributes of the inserts become the inline syntax, and
+    /// the block attributes stored on the newline, e.g. `header` and `list`, become the prefix of the
+    /// line that the newline ends. The other attributes can't be represented and are dropped. The
+    /// adjacent inserts with the same inline attributes are wrapped together, e.g. `**ab**` rather
+    /// than `**a****b**`. The Markdown control characters in the plain text and the block markers at
+    /// the beginning of the line are escaped with a backslash.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        let mut spans = vec![];
+        let mut ordered_index = 0;
+        for op in &self.ops {
+            let insert = match op {
+                Operation::Insert(insert) => insert,
+                _ => continue,
+            };
+
+            if let Some(embed) = &insert.embed {
+                match embed.value.as_str() {
+                    Some(src) if embed.ty == "image" => spans.push(Span::embed(format!("![]({})", src))),
+                    _ => {}
+                }
+                continue;
+            }
+
+            let style = InlineStyle::new(&insert.attributes);
+            for (i, text) in insert.s.split('\n').enumerate() {
+                if i > 0 {
+                    markdown.push_str(&block_prefix(&insert.attributes, &mut ordered_index));
+                    markdown.push_str(&line_markdown(&spans));
+                    markdown.push('\n');
+                    spans.clear();
+                }
+                if !text.is_empty() {
+                    spans.push(Span::text(text, style));
+                }
+            }
+        }
+        markdown.push_str(&line_markdown(&spans));
+        markdown
+    }
+
+    /// Parses the Markdown into the document delta. The headings, bullet/ordered/task lists and
+    /// block quotes become the block attributes of the newline that ends the line, and the bold,
+    /// italic, inline code and links become the attributes of the inserts. The syntax that isn't
+    /// supported or isn't closed is kept as the literal text.
+    pub fn from_markdown(markdown: &str) -> Self {
+        let mut delta = RichTextDelta::new();
+        let lines = markdown.split('\n').collect::<Vec<&str>>();
+        for (i, line) in lines.iter().enumerate() {
+            let (block_attributes, text) = parse_block_prefix(line);
+            let chars = text.chars().collect::<Vec<char>>();
+            parse_inline(&chars, &RichTextAttributes::default(), &mut delta);
+            if i + 1 < lines.len() || !block_attributes.is_empty() {
+                delta.insert("\n", block_attributes);
+            }
+        }
+        delta
+    }
+}
+
+pub(crate) fn attribute_str<'a>(attributes: &'a RichTextAttributes, key: &RichTextAttributeKey) -> Option<&'a str> {
+    attributes.get(key).and_then(|value| value.as_str())
+}
+
+pub(crate) fn attribute_bool(attributes: &RichTextAttributes, key: &RichTextAttributeKey) -> bool {
+    attributes.get(key).and_then(|value| value.as_bool()).unwrap_or(false)
+}
+
+pub(crate) fn attribute_usize(attributes: &RichTextAttributes, key: &RichTextAttributeKey) -> Option<usize> {
+    attributes.get(key).and_then(|value| value.as_usize())
+}
+
+fn block_prefix(attributes: &RichTextAttributes, ordered_index: &mut usize) -> String {
+    if let Some(list) = attribute_str(attributes, &RichTextAttributeKey::List) {
+        if list == "ordered" {
+            *ordered_index += 1;
+            return format!("{}. ", ordered_index);
+        }
+        *ordered_index = 0;
+        let prefix = match list {
+            "checked" => "- [x] ",
+            "unchecked" => "- [ ] ",
+            _ => "- ",
+        };
+        return prefix.to_owned();
+    }
+
+    *ordered_index = 0;
+    if let Some(level) = attribute_usize(attributes, &RichTextAttributeKey::Header) {
+        return format!("{} ", "#".repeat(level));
+    }
+
+    if attribute_bool(attributes, &RichTextAttributeKey::BlockQuote) {
+        return "> ".to_owned();
+    }
+    String::new()
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+struct InlineStyle<'a> {
+    link: Option<&'a str>,
+    bold: bool,
+    italic: bool,
+    code: bool,
+}
+
+impl<'a> InlineStyle<'a> {
+    fn new(attributes: &'a RichTextAttributes) -> Self {
+        InlineStyle {
+            link: attribute_str(attributes, &RichTextAttributeKey::Link),
+            bold: attribute_bool(attributes, &RichTextAttributeKey::Bold),
+            italic: attribute_bool(attributes, &RichTextAttributeKey::Italic),
+            code: attribute_bool(attributes, &RichTextAttributeKey::InlineCode),
+        }
+    }
+}
+
+// The piece of the line: the text of the insert with its inline style, or the Markdown of the
+// embed, which is written as it is.
+struct Span<'a> {
+    text: Cow<'a, str>,
+    style: InlineStyle<'a>,
+    is_embed: bool,
+}
+
+impl<'a> Span<'a> {
+    fn text(text: &'a str, style: InlineStyle<'a>) -> Self {
+        Span {
+            text: Cow::Borrowed(text),
+            style,
+            is_embed: false,
+        }
+    }
+
+    fn embed(markdown: String) -> Self {
+        Span {
+            text: Cow::Owned(markdown),
+            style: InlineStyle::default(),
+            is_embed: true,
+        }
+    }
+}
+
+// Splits the spans into the runs of the consecutive spans with the same key.
+fn runs<'s, 'a, K: PartialEq>(spans: &'s [Span<'a>], key: impl Fn(&Span<'a>) -> K) -> Vec<&'s [Span<'a>]> {
+    let mut runs = vec![];
+    let mut start = 0;
+    for i in 1..=spans.len() {
+        if i == spans.len() || key(&spans[i]) != key(&spans[start]) {
+            runs.push(&spans[start..i]);
+            start = i;
+        }
+    }
+    runs
+}
+
+// The syntax is nested from the outside in: link, bold, italic and code, so each run of the
+// spans sharing the outer syntax is wrapped once.
+fn line_markdown(spans: &[Span]) -> String {
+    let mut line = String::new();
+    for run in runs(spans, |span| span.style.link) {
+        let text = wrap_runs(
+            run,
+            |span| span.style.bold,
+            "**",
+            |run| wrap_runs(run, |span| span.style.italic, "*", code_markdown),
+        );
+        match run[0].style.link {
+            Some(link) => line.push_str(&format!("[{}]({})", text, link)),
+            None => line.push_str(&text),
+        }
+    }
+    escape_block_marker(line)
+}
+
+fn wrap_runs<'a>(
+    spans: &[Span<'a>],
+    is_wrapped: impl Fn(&Span<'a>) -> bool,
+    marker: &str,
+    inner: impl Fn(&[Span<'a>]) -> String,
+) -> String {
+    let mut markdown = String::new();
+    for run in runs(spans, &is_wrapped) {
+        let text = inner(run);
+        match is_wrapped(&run[0]) {
+            true => markdown.push_str(&format!("{}{}{}", marker, text, marker)),
+            false => markdown.push_str(&text),
+        }
+    }
+    markdown
+}
+
+fn code_markdown(spans: &[Span]) -> String {
+    let mut markdown = String::new();
+    for run in runs(spans, |span| span.style.code) {
+        if run[0].style.code {
+            let code = run.iter().map(|span| span.text.as_ref()).collect::<String>();
+            markdown.push_str(&code_span(&code));
+            continue;
+        }
+        for span in run {
+            match span.is_embed {
+                true => markdown.push_str(&span.text),
+                false => markdown.push_str(&escape_markdown(&span.text)),
+            }
+        }
+    }
+    markdown
+}
+
+// The inline code can't escape the backtick, so the fence is made longer than the longest run of
+// the backticks in the code. The code that starts or ends with a backtick is padded with the
+// spaces, which the parser strips.
+fn code_span(code: &str) -> String {
+    let longest = code.split(|c| c != '`').map(|run| run.len()).max().unwrap_or(0);
+    let fence = "`".repeat(longest + 1);
+    match code.starts_with('`') || code.ends_with('`') {
+        true => format!("{} {} {}", fence, code, fence),
+        false => format!("{}{}{}", fence, code, fence),
+    }
+}
+
+// Escapes the text at the beginning of the line that would be parsed as the block syntax, i.e. the
+// header, list or quote marker.
+fn escape_block_marker(mut line: String) -> String {
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && line[digits..].starts_with(['.', ')'].as_ref()) {
+        line.insert(digits, '\\');
+    } else if line.starts_with(['#', '-', '+', '>'].as_ref()) {
+        line.insert(0, '\\');
+    }
+    line
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn parse_block_prefix(line: &str) -> (RichTextAttributes, &str) {
+    let mut attributes = RichTextAttributes::new();
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if (1..=6).contains(&level) && line[level..].starts_with(' ') {
+        attributes.add(RichTextAttribute::Header(level));
+        return (attributes, &line[level + 1..]);
+    }
+
+    let prefixes = vec![
+        ("- [x] ", RichTextAttribute::Checked(true)),
+        ("- [ ] ", RichTextAttribute::UnChecked(true)),
+        ("- ", RichTextAttribute::Bullet(true)),
+        ("* ", RichTextAttribute::Bullet(true)),
+        ("> ", RichTextAttribute::BlockQuote(true)),
+    ];
+    for (prefix, attribute) in prefixes {
+        if let Some(text) = line.strip_prefix(prefix) {
+            attributes.add(attribute);
+            return (attributes, text);
+        }
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        if let Some(text) = line[digits..].strip_prefix(". ") {
+            attributes.add(RichTextAttribute::Ordered(true));
+            return (attributes, text);
+        }
+    }
+    (attributes, line)
+}
+
+fn parse_inline(chars: &[char], attributes: &RichTextAttributes, delta: &mut RichTextDelta) {
+    let mut text = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' && i + 1 < chars.len() {
+            text.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+
+        // The span is (start, end, next, attribute): its content is chars[start..end] and the parsing
+        // goes on from the next.
+        let span =
+            match c {
+                '`' => find_closing(chars, i + 1, &['`'])
+                    .map(|end| (i + 1, end, end + 1, RichTextAttribute::InlineCode(true))),
+                '*' if chars.get(i + 1) == Some(&'*') => find_closing(chars, i + 2, &['*', '*'])
+                    .map(|end| (i + 2, end, end + 2, RichTextAttribute::Bold(true))),
+                '*' | '_' => {
+                    find_closing(chars, i + 1, &[c]).map(|end| (i + 1, end, end + 1, RichTextAttribute::Italic(true)))
+                }
+                '[' => find_link(chars, i),
+                _ => None,
+            };
+
+        match span {
+            None => {
+                text.push(c);
+                i += 1;
+            }
+            Some((start, end, next, attribute)) => {
+                delta.insert(&text, attributes.clone());
+                text.clear();
+
+                let is_code = attribute.key == RichTextAttributeKey::InlineCode;
+                let mut span_attributes = attributes.clone();
+                span_attributes.add(attribute);
+                if is_code {
+                    let code = chars[start..end].iter().collect::<String>();
+                    delta.insert(&code, span_attributes);
+                } else {
+                    parse_inline(&chars[start..end], &span_attributes, delta);
+                }
+                i = next;
+            }
+        }
+    }
+    delta.insert(&text, attributes.clone());
+}
+
+// Finds the closing delimiter that isn't escaped and isn't followed by the same character, so the
+// `***text***` is closed at the last three stars. The empty span isn't a match.
+fn find_closing(chars: &[char], from: usize, delimiter: &[char]) -> Option<usize> {
+    let mut i = from;
+    while i < chars.len() {
+        if chars[i] == '\\' {
+            i += 2;
+            continue;
+        }
+        if chars[i..].starts_with(delimiter) && chars.get(i + delimiter.len()) != Some(&delimiter[0]) {
+            return if i > from { Some(i) } else { None };
+        }
+        i += 1;
+    }
+    None
+}
+
+fn find_link(chars: &[char], i: usize) -> Option<(usize, usize, usize, RichTextAttribute)> {
+    let end = find_closing(chars, i + 1, &[']'])?;
+    if chars.get(end + 1) != Some(&'(') {
+        return None;
+    }
+    let url_end = find_closing(chars, end + 2, &[')'])?;
+    let url = chars[end + 2..url_end].iter().collect::<String>();
+    Some((i + 1, end, url_end + 1, RichTextAttribute::Link(&url)))
+}
@@ -1,6 +1,10 @@
 mod attributes;
 mod attributes_serde;
 mod builder;
+mod compact;
+mod html;
+mod markdown;
+mod quill;
 
 #[macro_use]
 mod macros;
@@ -0,0 +1,88 @@
+use crate::{
+    core::Embed,
+    errors::{ErrorBuilder, OTError, OTErrorCode},
+    rich_text::{RichTextAttributeKey, RichTextAttributeValue, RichTextAttributes, RichTextDelta},
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use serde_json::Value;
+
+impl RichTextDelta {
+    /// Imports the delta of the Quill editor, either the `{"ops": [...]}` object or the array of
+    /// the operations. Unlike `from_delta_str`, it accepts the content written by the older Quill
+    /// versions and the other editors:
+    /// * the attribute values that are the floats, the arrays or the objects are kept as their JSON
+    ///   text, e.g. `"12.5"`.
+    /// * the lengths of the retains and deletes may be the strings, e.g. `"3"`.
+    /// * the attributes of the unknown keys are kept with the `RichTextAttributeKey::Custom` keys,
+    ///   so they survive the round-trip through `to_quill_delta_str` and `to_delta_str`. The
+    ///   unregistered keys have the `Ignore` scope.
+    ///
+    /// Returns the `SerdeError` error if the shape of the delta or of an operation is invalid.
+    pub fn from_quill_ops(value: Value) -> Result<Self, OTError> {
+        let ops = match value {
+            Value::Array(ops) => ops,
+            Value::Object(mut map) => match map.remove("ops") {
+                Some(Value::Array(ops)) => ops,
+                _ => return Err(quill_error("Expected the array of the ops")),
+            },
+            _ => return Err(quill_error("Expected the object with the ops or the array of the ops")),
+        };
+
+        let mut delta = RichTextDelta::with_capacity(ops.len());
+        for (index, op) in ops.iter().enumerate() {
+            let invalid_op = || quill_error(format!("Invalid operation at index {}: {}", index, op));
+            let op = op.as_object().ok_or_else(invalid_op)?;
+            let attributes = quill_attributes(op.get("attributes"));
+            match (op.get("insert"), op.get("retain"), op.get("delete")) {
+                (Some(Value::String(s)), None, None) => delta.insert(s, attributes),
+                (Some(Value::Object(embed)), None, None) => {
+                    let (ty, value) = embed.iter().next().ok_or_else(invalid_op)?;
+                    delta.insert_embed(Embed::new(ty.as_str(), value.clone()), attributes);
+                }
+                (None, Some(n), None) => delta.retain(quill_len(n).ok_or_else(invalid_op)?, attributes),
+                (None, None, Some(n)) => delta.delete(quill_len(n).ok_or_else(invalid_op)?),
+                _ => return Err(invalid_op()),
+            }
+        }
+        Ok(delta)
+    }
+}
+
+fn quill_error<T: Into<String>>(msg: T) -> OTError {
+    ErrorBuilder::new(OTErrorCode::SerdeError).msg(msg).build()
+}
+
+fn quill_len(value: &Value) -> Option<usize> {
+    match value {
+        Value::Number(n) => n.as_u64().map(|n| n as usize),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn quill_attributes(value: Option<&Value>) -> RichTextAttributes {
+    let map = match value {
+        Some(Value::Object(map)) => map,
+        _ => return RichTextAttributes::default(),
+    };
+    let mut attributes = RichTextAttributes::new();
+    for (key, value) in map {
+        let key = RichTextAttributeKey::from_name(key).unwrap_or_else(|| RichTextAttributeKey::Custom(key.clone()));
+        let value = match value {
+            Value::Null => RichTextAttributeValue::Null,
+            Value::Bool(b) => RichTextAttributeValue::Bool(*b),
+            Value::Number(n) => match n.as_i64() {
+                Some(n) => RichTextAttributeValue::Int(n),
+                None => RichTextAttributeValue::Str(n.to_string()),
+            },
+            Value::String(s) => s.as_str().into(),
+            value => RichTextAttributeValue::Str(value.to_string()),
+        }
+        .typed_for(&key);
+        attributes.insert(key, value);
+    }
+    attributes
+}
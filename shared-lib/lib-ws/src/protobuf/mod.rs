@@ -0,0 +1,8 @@
+#![cfg_attr(rustfmt, rustfmt::skip)]
+// Auto-generated, do not edit
+
+mod msg;
+pub use msg::*;
+
+mod errors;
+pub use errors::*;